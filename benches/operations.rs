@@ -0,0 +1,174 @@
+// 基准测试: cargo bench [-- <名称过滤>]
+// 每次运行把结果以 JSON Lines 追加到 target/bench-results.jsonl (可用 SPARSE_BENCH_OUTPUT 指定),
+// SPARSE_BENCH_LABEL 会写进每条记录, 一般填提交号, 便于对比不同版本
+use std::fs::{self, OpenOptions};
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use sparse_matrix_proj::{SparseMatrix, SparseMatrixBuilder};
+
+// 每个用例至少跑 MIN_SAMPLES 次, 累计超过 TIME_BUDGET 后停止
+const MIN_SAMPLES: usize = 5;
+const MAX_SAMPLES: usize = 1000;
+const TIME_BUDGET: Duration = Duration::from_millis(300);
+
+const SIZES: [usize; 3] = [100, 1000, 4000];
+const DENSITIES: [f64; 3] = [0.001, 0.005, 0.02];
+// 稠密打印是 O(rows * cols), 只测小矩阵
+const PRINT_SIZES: [usize; 2] = [100, 400];
+
+struct Record {
+    name: String,
+    op: &'static str,
+    n: usize,
+    density: f64,
+    nnz: usize,
+    samples: Vec<Duration>,
+}
+
+impl Record {
+    fn to_json(&self, label: &str, timestamp: u64) -> String {
+        let mut ns: Vec<u128> = self.samples.iter().map(Duration::as_nanos).collect();
+        ns.sort_unstable();
+        let mean = ns.iter().sum::<u128>() / ns.len() as u128;
+        format!(
+            "{{\"timestamp\":{},\"label\":\"{}\",\"name\":\"{}\",\"op\":\"{}\",\"n\":{},\"density\":{},\"nnz\":{},\
+             \"samples\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"max_ns\":{}}}",
+            timestamp, json_escape(label), self.name, self.op, self.n, self.density, self.nnz,
+            ns.len(), ns[0], ns[ns.len() / 2], mean, ns[ns.len() - 1]
+        )
+    }
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+struct Harness {
+    filter: Option<String>,
+    records: Vec<Record>,
+}
+
+impl Harness {
+    // setup 的耗时不计入; routine 的返回值交给 black_box, 防止被优化掉
+    fn bench<I, O>(
+        &mut self,
+        op: &'static str,
+        n: usize,
+        density: f64,
+        nnz: usize,
+        mut setup: impl FnMut() -> I,
+        mut routine: impl FnMut(I) -> O,
+    ) {
+        let name = format!("{}/n={}/d={}", op, n, density);
+        if self.filter.as_ref().is_some_and(|f| !name.contains(f.as_str())) {
+            return;
+        }
+        black_box(routine(setup()));
+
+        let mut samples = Vec::new();
+        let mut elapsed = Duration::ZERO;
+        while samples.len() < MIN_SAMPLES || (elapsed < TIME_BUDGET && samples.len() < MAX_SAMPLES) {
+            let input = setup();
+            let start = Instant::now();
+            let output = routine(input);
+            let t = start.elapsed();
+            black_box(output);
+            samples.push(t);
+            elapsed += t;
+        }
+
+        let median = {
+            let mut sorted = samples.clone();
+            sorted.sort_unstable();
+            sorted[sorted.len() / 2]
+        };
+        println!("{:<36} nnz {:>8}  median {:>12.3?}  ({} samples)", name, nnz, median, samples.len());
+        self.records.push(Record { name, op, n, density, nnz, samples });
+    }
+}
+
+// 打乱三元组顺序, 让 from_triplets 真正排序一次; 与生成器一样用固定种子
+fn shuffled(mut triplets: Vec<(usize, usize, i32)>, seed: u64) -> Vec<(usize, usize, i32)> {
+    let mut state = seed;
+    for i in (1..triplets.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        triplets.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+    triplets
+}
+
+fn main() -> io::Result<()> {
+    // cargo bench 会传入 --bench, 其余第一个参数作为名称过滤
+    let filter = std::env::args().skip(1).find(|a| !a.starts_with('-'));
+    let mut h = Harness { filter, records: Vec::new() };
+
+    for &n in &SIZES {
+        for &density in &DENSITIES {
+            let a: SparseMatrix = SparseMatrix::random_uniform(n, n, density, 1);
+            let b: SparseMatrix = SparseMatrix::random_uniform(n, n, density, 2);
+            let sorted: Vec<(usize, usize, i32)> = a.iter().collect();
+            let unsorted = shuffled(sorted.clone(), 3);
+            let nnz = sorted.len();
+            let x: Vec<i32> = (0..n).map(|i| (i % 7) as i32 - 3).collect();
+
+            h.bench("from_triplets", n, density, nnz, || unsorted.clone(), |t| SparseMatrix::from_triplets(n, n, t));
+            h.bench("builder_sorted", n, density, nnz, || (), |_| {
+                let mut builder = SparseMatrixBuilder::new(n, n);
+                for &(r, c, v) in &sorted {
+                    builder.push(r, c, v).unwrap();
+                }
+                builder.build().unwrap()
+            });
+            h.bench("add", n, density, nnz, || (), |_| a.add(&b));
+            // multiply 对 A 的每一行都扫描 B 的全部元素, 最大规模只测最稀疏的情形
+            if n < 4000 || density <= 0.001 {
+                h.bench("multiply", n, density, nnz, || (), |_| a.multiply(&b));
+            }
+            h.bench("mul_vec", n, density, nnz, || (), |_| a.mul_vec(&x));
+        }
+    }
+
+    for &n in &PRINT_SIZES {
+        let density = 0.02;
+        let a: SparseMatrix = SparseMatrix::random_uniform(n, n, density, 1);
+        let nnz = a.iter().len();
+        h.bench("print", n, density, nnz, || (), |_| a.write_dense(io::sink()).unwrap());
+    }
+
+    write_results(&h.records)
+}
+
+fn write_results(records: &[Record]) -> io::Result<()> {
+    if records.is_empty() {
+        return Ok(());
+    }
+    let path = std::env::var_os("SPARSE_BENCH_OUTPUT")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target").join("bench-results.jsonl"));
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let label = std::env::var("SPARSE_BENCH_LABEL").unwrap_or_default();
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    for record in records {
+        writeln!(file, "{}", record.to_json(&label, timestamp))?;
+    }
+    println!("{} results appended to {}", records.len(), path.display());
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::process;
+
+use sparse_matrix_proj::{Format, Session};
+
+const USAGE: &str = "\
+Usage: sparse-calc [OPTIONS] [STATEMENT]...
+
+Evaluates sparse matrix statements such as \"C = A * B + A'\".
+
+Options:
+  -l, --load NAME=PATH     load a matrix (.mtx/.mm = Matrix Market, .smx = binary, otherwise triplets)
+  -s, --script FILE        run statements from FILE, one per line
+  -o, --output NAME=PATH   write matrix NAME to PATH when done
+  -f, --format FMT         output format: mm, triplet, binary or dense (stdout only)
+  -h, --help               show this help
+
+Statements:
+  NAME = EXPR              operators: + - * unary -, postfix ' (transpose), parentheses
+  EXPR                     result is stored in 'ans'
+  load NAME PATH [FORMAT]
+  save NAME PATH [FORMAT]
+
+Without --output the last assigned matrix is written to stdout.";
+
+struct Options {
+    loads: Vec<(String, String)>,
+    scripts: Vec<String>,
+    outputs: Vec<(String, String)>,
+    format: Option<String>,
+    statements: Vec<String>,
+}
+
+fn split_binding(arg: &str, flag: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, path)) if !name.is_empty() && !path.is_empty() => Ok((name.to_string(), path.to_string())),
+        _ => Err(format!("{} expects NAME=PATH, got '{}'", flag, arg)),
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut opts = Options { loads: Vec::new(), scripts: Vec::new(), outputs: Vec::new(), format: None, statements: Vec::new() };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().cloned().ok_or_else(|| format!("{} expects a value", arg));
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            "-l" | "--load" => opts.loads.push(split_binding(&value()?, arg)?),
+            "-s" | "--script" => opts.scripts.push(value()?),
+            "-o" | "--output" => opts.outputs.push(split_binding(&value()?, arg)?),
+            "-f" | "--format" => opts.format = Some(value()?),
+            s if s.starts_with('-') && s.len() > 1 => return Err(format!("unknown option '{}'", s)),
+            _ => opts.statements.push(arg.clone()),
+        }
+    }
+    Ok(opts)
+}
+
+fn run(opts: Options) -> Result<(), String> {
+    let dense = opts.format.as_deref() == Some("dense");
+    let format = match &opts.format {
+        Some(f) if !dense => Some(f.parse::<Format>()?),
+        _ => None,
+    };
+
+    let mut session = Session::new();
+    let mut last: Option<String> = None;
+
+    for (name, path) in &opts.loads {
+        session.load(name, path, None).map_err(|e| e.to_string())?;
+    }
+    for script in &opts.scripts {
+        let text = fs::read_to_string(script).map_err(|e| format!("cannot read '{}': {}", script, e))?;
+        for (i, line) in text.lines().enumerate() {
+            let assigned = session.execute(line).map_err(|e| format!("{}:{}: {}", script, i + 1, e))?;
+            last = assigned.or(last);
+        }
+    }
+    for stmt in &opts.statements {
+        let assigned = session.execute(stmt).map_err(|e| format!("'{}': {}", stmt, e))?;
+        last = assigned.or(last);
+    }
+
+    if !opts.outputs.is_empty() {
+        if dense {
+            return Err("the dense format can only be written to stdout".to_string());
+        }
+        for (name, path) in &opts.outputs {
+            session.save(name, path, format).map_err(|e| e.to_string())?;
+        }
+        return Ok(());
+    }
+
+    if let Some(name) = last {
+        let matrix = session.get(&name).expect("assigned matrices are kept in the session");
+        if dense {
+            matrix.print();
+        } else {
+            matrix.write(io::stdout().lock(), format.unwrap_or(Format::MatrixMarket))
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = parse_args(&args).and_then(run);
+    if let Err(msg) = result {
+        eprintln!("sparse-calc: {}", msg);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,180 @@
+use std::io::{self, BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+
+use sparse_matrix_proj::{SparseMatrix, Session, SpyStyle};
+
+const HELP: &str = "\
+Commands:
+  new NAME ROWS COLS           create an all-zero matrix
+  set NAME ROW COL VALUE       write one element (1-based; 0 removes it)
+  get NAME ROW COL             read one element
+  print NAME                   print as a dense grid
+  stats NAME                   sparsity statistics
+  spy NAME [ascii|braille]     draw the nonzero pattern
+  transpose NAME [DEST]        DEST = NAME' (DEST defaults to NAME)
+  load NAME PATH [FORMAT]      FORMAT is mm, triplet or binary, default from extension
+  save NAME PATH [FORMAT]
+  NAME = EXPR                  operators: + - * unary -, postfix ' (transpose), parentheses
+  EXPR                         evaluate and store in 'ans'
+  list                         list matrices
+  history                      list previous commands
+  !N  /  !!                    rerun history entry N / the last entry
+  help, quit";
+
+enum Flow {
+    Continue,
+    Quit,
+}
+
+struct Repl {
+    session: Session,
+    history: Vec<String>,
+}
+
+fn parse_num(token: Option<&&str>, what: &str) -> Result<usize, String> {
+    let token = token.ok_or_else(|| format!("missing {}", what))?;
+    token.parse().map_err(|_| format!("invalid {} '{}'", what, token))
+}
+
+impl Repl {
+    fn matrix(&self, name: &str) -> Result<&SparseMatrix, String> {
+        self.session.get(name).ok_or_else(|| format!("unknown matrix '{}'", name))
+    }
+
+    fn checked_position(&self, name: &str, row: usize, col: usize) -> Result<(), String> {
+        let (rows, cols) = self.matrix(name)?.dims();
+        if row == 0 || row > rows || col == 0 || col > cols {
+            return Err(format!("position ({}, {}) out of bounds for {}x{} matrix '{}'", row, col, rows, cols, name));
+        }
+        Ok(())
+    }
+
+    fn describe(&self, name: &str) {
+        if let Some(m) = self.session.get(name) {
+            let (rows, cols) = m.dims();
+            println!("{}: {}x{}, {} nonzeros", name, rows, cols, m.stats().nnz);
+        }
+    }
+
+    // 历史记录引用先展开成原命令, 再记录并执行
+    fn expand_history(&self, line: &str) -> Result<String, String> {
+        let Some(rest) = line.strip_prefix('!') else {
+            return Ok(line.to_string());
+        };
+        let index = if rest == "!" {
+            self.history.len()
+        } else {
+            rest.parse::<usize>().map_err(|_| format!("invalid history reference '{}'", line))?
+        };
+        match index.checked_sub(1).and_then(|i| self.history.get(i)) {
+            Some(entry) => {
+                println!("{}", entry);
+                Ok(entry.clone())
+            }
+            None => Err(format!("no history entry {}", index)),
+        }
+    }
+
+    fn handle(&mut self, line: &str) -> Result<Flow, String> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(Flow::Continue);
+        }
+        let line = self.expand_history(line)?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words[0] {
+            "quit" | "exit" => return Ok(Flow::Quit),
+            "help" => println!("{}", HELP),
+            "history" => {
+                for (i, entry) in self.history.iter().enumerate() {
+                    println!("{:4}  {}", i + 1, entry);
+                }
+                return Ok(Flow::Continue);
+            }
+            "list" => {
+                for name in self.session.names() {
+                    self.describe(name);
+                }
+            }
+            "new" => {
+                let name = words.get(1).ok_or("usage: new NAME ROWS COLS")?;
+                let rows = parse_num(words.get(2), "row count")?;
+                let cols = parse_num(words.get(3), "column count")?;
+                self.session.insert(name, SparseMatrix::new(rows, cols));
+                self.describe(name);
+            }
+            "set" => {
+                let name = *words.get(1).ok_or("usage: set NAME ROW COL VALUE")?;
+                let row = parse_num(words.get(2), "row")?;
+                let col = parse_num(words.get(3), "column")?;
+                let token = words.get(4).ok_or("missing value")?;
+                let value: i32 = token.parse().map_err(|_| format!("invalid value '{}'", token))?;
+                self.checked_position(name, row, col)?;
+                self.session.get_mut(name).expect("checked above").set(row, col, value);
+            }
+            "get" => {
+                let name = *words.get(1).ok_or("usage: get NAME ROW COL")?;
+                let row = parse_num(words.get(2), "row")?;
+                let col = parse_num(words.get(3), "column")?;
+                self.checked_position(name, row, col)?;
+                println!("{}", self.matrix(name)?.get(row, col));
+            }
+            "print" => self.matrix(words.get(1).ok_or("usage: print NAME")?)?.print(),
+            "spy" => {
+                let m = self.matrix(words.get(1).ok_or("usage: spy NAME [ascii|braille]")?)?;
+                let style = match words.get(2).copied() {
+                    None | Some("ascii") => SpyStyle::Ascii,
+                    Some("braille") => SpyStyle::Braille,
+                    Some(other) => return Err(format!("unknown spy style '{}'", other)),
+                };
+                print!("{}", m.spy(64, 24, style));
+            }
+            "stats" => println!("{}", self.matrix(words.get(1).ok_or("usage: stats NAME")?)?.stats()),
+            "transpose" => {
+                let name = *words.get(1).ok_or("usage: transpose NAME [DEST]")?;
+                let dest = words.get(2).copied().unwrap_or(name);
+                let t = self.matrix(name)?.transpose();
+                self.session.insert(dest, t);
+                self.describe(dest);
+            }
+            _ => {
+                let assigned = self.session.execute(&line).map_err(|e| e.to_string())?;
+                match assigned.as_deref() {
+                    Some("ans") => self.matrix("ans")?.print(),
+                    Some(name) => self.describe(name),
+                    None => {}
+                }
+            }
+        }
+        self.history.push(line);
+        Ok(Flow::Continue)
+    }
+}
+
+fn main() {
+    println!("Sparse matrix shell. Type 'help' for commands, 'quit' to leave.");
+    let mut repl = Repl { session: Session::new(), history: Vec::new() };
+    let stdin = io::stdin();
+    let mut line = String::new();
+    loop {
+        print!("sparse> ");
+        io::stdout().flush().expect("stdout is writable");
+        line.clear();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("error: {}", e);
+                break;
+            }
+        }
+        // 运算错误都以 Err 返回; 万一有命令 panic, 也只放弃这一条命令, 不退出 shell
+        match panic::catch_unwind(AssertUnwindSafe(|| repl.handle(&line))) {
+            Ok(Ok(Flow::Continue)) => {}
+            Ok(Ok(Flow::Quit)) => break,
+            Ok(Err(msg)) => println!("error: {}", msg),
+            Err(_) => println!("error: command aborted"),
+        }
+    }
+}
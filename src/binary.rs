@@ -0,0 +1,253 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use crate::{Scalar, SparseMatrix};
+
+// 二进制格式 (全部小端):
+//   0   4  魔数 "SPMX"
+//   4   2  版本号 u16
+//   6   1  值类型 (1 = i32, 2 = i64, 3 = f32, 4 = f64)
+//   7   1  保留, 为 0
+//   8   8  rows u64
+//  16   8  cols u64
+//  24   8  nnz u64
+//  32      行指针 u64 x (rows + 1), 第 i 行的元素位于 [ptr[i-1], ptr[i])
+//          列号 u64 x nnz (1 起始, 每行内严格递增)
+//          值 x nnz, 每个占值类型的宽度
+//  末尾 4  CRC-32 校验和, 覆盖之前的全部字节
+const MAGIC: &[u8; 4] = b"SPMX";
+const VERSION: u16 = 1;
+const HEADER_LEN: usize = 32;
+
+// 可以写进二进制格式的元素类型: 类型编号和小端编码
+pub trait BinaryScalar: Scalar {
+    const TYPE_TAG: u8;
+    const WIDTH: usize;
+
+    fn write_le(self, buf: &mut Vec<u8>);
+
+    // bytes 的长度恰为 WIDTH
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_binary_scalar {
+    ($t:ty, $tag:expr) => {
+        impl BinaryScalar for $t {
+            const TYPE_TAG: u8 = $tag;
+            const WIDTH: usize = size_of::<$t>();
+
+            fn write_le(self, buf: &mut Vec<u8>) {
+                buf.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_le(bytes: &[u8]) -> Self {
+                <$t>::from_le_bytes(bytes.try_into().unwrap())
+            }
+        }
+    };
+}
+
+impl_binary_scalar!(i32, 1);
+impl_binary_scalar!(i64, 2);
+impl_binary_scalar!(f32, 3);
+impl_binary_scalar!(f64, 4);
+
+#[derive(Debug)]
+pub enum BinaryError {
+    Io(io::Error),
+    BadMagic,
+    UnsupportedVersion(u16),
+    // 文件中的值类型与读取时要求的元素类型不同
+    UnsupportedValueType(u8),
+    Truncated { expected: usize, actual: usize },
+    TrailingData { expected: usize, actual: usize },
+    ChecksumMismatch { stored: u32, computed: u32 },
+    Corrupt(String),
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryError::Io(e) => write!(f, "{}", e),
+            BinaryError::BadMagic => write!(f, "not a sparse matrix binary file (bad magic bytes)"),
+            BinaryError::UnsupportedVersion(v) => write!(f, "unsupported format version {} (expected {})", v, VERSION),
+            BinaryError::UnsupportedValueType(t) => write!(f, "unsupported value type {}", t),
+            BinaryError::Truncated { expected, actual } => {
+                write!(f, "file truncated: expected {} bytes, found {}", expected, actual)
+            }
+            BinaryError::TrailingData { expected, actual } => {
+                write!(f, "unexpected trailing data: expected {} bytes, found {}", expected, actual)
+            }
+            BinaryError::ChecksumMismatch { stored, computed } => {
+                write!(f, "checksum mismatch: stored {:08x}, computed {:08x}", stored, computed)
+            }
+            BinaryError::Corrupt(msg) => write!(f, "corrupted data: {}", msg),
+        }
+    }
+}
+
+impl Error for BinaryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BinaryError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for BinaryError {
+    fn from(e: io::Error) -> Self {
+        BinaryError::Io(e)
+    }
+}
+
+// CRC-32 (IEEE 802.3, 反射多项式 0xEDB88320)
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            k += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, &b| CRC_TABLE[((crc ^ b as u32) & 0xFF) as usize] ^ (crc >> 8))
+}
+
+fn u64_at(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
+
+// 头部给出的尺寸换算成 usize, 溢出说明数据不可信
+fn to_usize(v: u64, what: &str) -> Result<usize, BinaryError> {
+    usize::try_from(v).map_err(|_| BinaryError::Corrupt(format!("{} {} does not fit in memory", what, v)))
+}
+
+impl<T: BinaryScalar> SparseMatrix<T> {
+    pub fn write_binary<W: Write>(&self, mut w: W) -> io::Result<()> {
+        let (rows, cols) = self.dims();
+        let entries = self.triplets();
+
+        let mut buf = Vec::with_capacity(HEADER_LEN + 8 * (rows + 1) + (8 + T::WIDTH) * entries.len() + 4);
+        buf.extend_from_slice(MAGIC);
+        buf.extend_from_slice(&VERSION.to_le_bytes());
+        buf.push(T::TYPE_TAG);
+        buf.push(0);
+        for v in [rows, cols, entries.len()] {
+            buf.extend_from_slice(&(v as u64).to_le_bytes());
+        }
+
+        let mut row_ptr = vec![0u64; rows + 1];
+        for &(r, _, _) in &entries {
+            row_ptr[r] += 1;
+        }
+        for i in 1..=rows {
+            row_ptr[i] += row_ptr[i - 1];
+        }
+        for p in row_ptr {
+            buf.extend_from_slice(&p.to_le_bytes());
+        }
+        for &(_, c, _) in &entries {
+            buf.extend_from_slice(&(c as u64).to_le_bytes());
+        }
+        for &(_, _, v) in &entries {
+            v.write_le(&mut buf);
+        }
+        let checksum = crc32(&buf);
+        buf.extend_from_slice(&checksum.to_le_bytes());
+
+        w.write_all(&buf)?;
+        w.flush()
+    }
+
+    // 先整体读入并核对长度和校验和, 再检查结构, 最后直接按行主序串起链表
+    pub fn read_binary<R: Read>(mut r: R) -> Result<SparseMatrix<T>, BinaryError> {
+        let mut bytes = Vec::new();
+        r.read_to_end(&mut bytes)?;
+
+        if bytes.len() < HEADER_LEN {
+            if bytes.len() >= 4 && &bytes[..4] != MAGIC {
+                return Err(BinaryError::BadMagic);
+            }
+            return Err(BinaryError::Truncated { expected: HEADER_LEN, actual: bytes.len() });
+        }
+        if &bytes[..4] != MAGIC {
+            return Err(BinaryError::BadMagic);
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != VERSION {
+            return Err(BinaryError::UnsupportedVersion(version));
+        }
+        if bytes[6] != T::TYPE_TAG {
+            return Err(BinaryError::UnsupportedValueType(bytes[6]));
+        }
+
+        let rows = to_usize(u64_at(&bytes, 8), "row count")?;
+        let cols = to_usize(u64_at(&bytes, 16), "column count")?;
+        // 行数受文件长度约束 (每行一个行指针), 列数没有; 按列分配的辅助数组放不进地址空间时视为损坏
+        if cols.checked_add(1).and_then(|n| n.checked_mul(size_of::<usize>())).is_none_or(|n| n > isize::MAX as usize) {
+            return Err(BinaryError::Corrupt(format!("column count {} is too large", cols)));
+        }
+        let nnz = to_usize(u64_at(&bytes, 24), "nonzero count")?;
+        let expected = rows.checked_add(1)
+            .and_then(|n| n.checked_mul(8))
+            .and_then(|n| nnz.checked_mul(8 + T::WIDTH).and_then(|m| n.checked_add(m)))
+            .and_then(|n| n.checked_add(HEADER_LEN + 4))
+            .ok_or_else(|| BinaryError::Corrupt("header sizes overflow".to_string()))?;
+        if bytes.len() < expected {
+            return Err(BinaryError::Truncated { expected, actual: bytes.len() });
+        }
+        if bytes.len() > expected {
+            return Err(BinaryError::TrailingData { expected, actual: bytes.len() });
+        }
+
+        let body = &bytes[..expected - 4];
+        let stored = u32::from_le_bytes(bytes[expected - 4..].try_into().unwrap());
+        let computed = crc32(body);
+        if stored != computed {
+            return Err(BinaryError::ChecksumMismatch { stored, computed });
+        }
+
+        let ptr_at = HEADER_LEN;
+        let col_at = ptr_at + 8 * (rows + 1);
+        let val_at = col_at + 8 * nnz;
+        let row_ptr: Vec<u64> = (0..=rows).map(|i| u64_at(body, ptr_at + 8 * i)).collect();
+        if row_ptr[0] != 0 || row_ptr[rows] != nnz as u64 {
+            return Err(BinaryError::Corrupt("row pointers do not span the entries".to_string()));
+        }
+
+        let mut entries = Vec::with_capacity(nnz);
+        for row in 1..=rows {
+            let (start, end) = (row_ptr[row - 1], row_ptr[row]);
+            if start > end || end > nnz as u64 {
+                return Err(BinaryError::Corrupt(format!("invalid row pointer at row {}", row)));
+            }
+            let mut prev_col = 0;
+            for k in start as usize..end as usize {
+                let col = u64_at(body, col_at + 8 * k);
+                if col == 0 || col > cols as u64 {
+                    return Err(BinaryError::Corrupt(format!("column {} out of bounds in row {}", col, row)));
+                }
+                if col <= prev_col {
+                    return Err(BinaryError::Corrupt(format!("columns not strictly increasing in row {}", row)));
+                }
+                prev_col = col;
+                let value = T::read_le(&body[val_at + T::WIDTH * k..val_at + T::WIDTH * (k + 1)]);
+                entries.push((row, col as usize, value));
+            }
+        }
+
+        let matrix = SparseMatrix::from_row_major(rows, cols, entries);
+        matrix.debug_validate("read_binary");
+        Ok(matrix)
+    }
+}
@@ -0,0 +1,160 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use crate::{Link, MatrixNode, Scalar, SparseMatrix, TripletError};
+
+// 同一坐标被 push 多次时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicatePolicy {
+    #[default]
+    Sum,
+    KeepFirst,
+    KeepLast,
+    // build 时返回 TripletError::Duplicate, 坐标为行主序下第一个重复的位置 (与 try_from_triplets 一致)
+    Reject,
+}
+
+// 增量构造 SparseMatrix:
+//   输入按 (row, col) 递增时, 每个元素直接接到 right 链末尾, 不保留三元组, 也不按列数分配任何东西;
+//   逆序到来的元素放进以坐标为键的 BTreeMap, 重复坐标在插入时就合并, 占用只与不同坐标的个数成正比.
+//   build 时把链表与 BTreeMap 按行主序归并一遍, 再串起 down 链.
+// 值为 0 的元素 (包括合并后为 0 的) 一直保留到 build 才丢弃, 因此结果与 push 的顺序无关
+pub struct SparseMatrixBuilder<T = i32> {
+    rows: usize,
+    cols: usize,
+    policy: DuplicatePolicy,
+    pushed: usize,
+    // 尚未串进链表的最后一个元素, 用来就地合并重复坐标
+    pending: Option<(usize, usize, T)>,
+    first: Option<Link<T>>,
+    last: Option<Link<T>>,
+    // 坐标都小于 pending; 与链表中同一坐标的元素一定是后 push 的
+    out_of_order: BTreeMap<(usize, usize), T>,
+    duplicate: Option<(usize, usize)>,
+}
+
+impl<T: Scalar> SparseMatrixBuilder<T> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        SparseMatrixBuilder {
+            rows,
+            cols,
+            policy: DuplicatePolicy::default(),
+            pushed: 0,
+            pending: None,
+            first: None,
+            last: None,
+            out_of_order: BTreeMap::new(),
+            duplicate: None,
+        }
+    }
+
+    pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    // 预计还会 push 的元素个数; 结点逐个分配, BTreeMap 也无法预留, 因此只作为提示, 不做任何事
+    pub fn reserve(&mut self, _additional: usize) {}
+
+    // 已 push 的元素个数 (含重复坐标)
+    pub fn len(&self) -> usize {
+        self.pushed
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pushed == 0
+    }
+
+    pub fn push(&mut self, row: usize, col: usize, value: T) -> Result<(), TripletError> {
+        if row == 0 || row > self.rows || col == 0 || col > self.cols {
+            return Err(TripletError::OutOfBounds { row, col, rows: self.rows, cols: self.cols });
+        }
+        self.pushed += 1;
+
+        match self.pending {
+            Some((r, c, v)) if (r, c) == (row, col) => {
+                self.pending = Some((r, c, self.merge(r, c, v, value)));
+            }
+            Some((r, c, _)) if (r, c) > (row, col) => {
+                let merged = match self.out_of_order.get(&(row, col)) {
+                    Some(&old) => self.merge(row, col, old, value),
+                    None => value,
+                };
+                self.out_of_order.insert((row, col), merged);
+            }
+            _ => {
+                self.flush_pending();
+                self.pending = Some((row, col, value));
+            }
+        }
+        Ok(())
+    }
+
+    fn merge(&mut self, row: usize, col: usize, old: T, new: T) -> T {
+        match self.policy {
+            DuplicatePolicy::Sum => old + new,
+            DuplicatePolicy::KeepFirst => old,
+            DuplicatePolicy::KeepLast => new,
+            DuplicatePolicy::Reject => {
+                self.duplicate = Some(self.duplicate.map_or((row, col), |d| d.min((row, col))));
+                old
+            }
+        }
+    }
+
+    // 把 pending 串到 right 链末尾; 零值也串进去, 之后可能还要与逆序的元素合并
+    fn flush_pending(&mut self) {
+        let Some((r, c, v)) = self.pending.take() else { return };
+        let node = Rc::new(RefCell::new(MatrixNode::new(r, c, v)));
+        match &self.last {
+            Some(last) => last.borrow_mut().right = Some(node.clone()),
+            None => self.first = Some(node.clone()),
+        }
+        self.last = Some(node);
+    }
+
+    pub fn build(mut self) -> Result<SparseMatrix<T>, TripletError> {
+        self.flush_pending();
+        self.last = None;
+        // 归并链表与逆序元素, 链表结点原样复用, 同时跳过值为零的结点
+        let mut extra = std::mem::take(&mut self.out_of_order).into_iter().peekable();
+        let mut p = self.first.take();
+        let mut nodes: Vec<Link<T>> = Vec::new();
+        loop {
+            let from_list = match (&p, extra.peek()) {
+                (Some(node), Some((key, _))) => {
+                    let n = node.borrow();
+                    (n.row, n.col) <= *key
+                }
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            let node = if from_list {
+                let node = p.take().unwrap();
+                p = node.borrow_mut().right.take();
+                let (r, c, old) = {
+                    let n = node.borrow();
+                    (n.row, n.col, n.value)
+                };
+                if let Some((_, v)) = extra.next_if(|(key, _)| *key == (r, c)) {
+                    node.borrow_mut().value = self.merge(r, c, old, v);
+                }
+                node
+            } else {
+                let ((r, c), v) = extra.next().unwrap();
+                Rc::new(RefCell::new(MatrixNode::new(r, c, v)))
+            };
+            if !node.borrow().value.is_zero() {
+                nodes.push(node);
+            }
+        }
+        if let Some((row, col)) = self.duplicate {
+            return Err(TripletError::Duplicate { row, col });
+        }
+        let matrix = SparseMatrix::from_row_major_nodes(self.rows, self.cols, nodes);
+        matrix.debug_validate("SparseMatrixBuilder::build");
+        Ok(matrix)
+    }
+}
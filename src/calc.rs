@@ -0,0 +1,339 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use crate::io::{Format, ReadError};
+use crate::{Expr, SparseMatrix};
+
+#[derive(Debug)]
+pub enum CalcError {
+    Syntax(String),
+    UnknownMatrix(String),
+    DimensionMismatch(String),
+    // 元素运算超出元素类型的范围
+    Overflow,
+    Read { path: String, source: ReadError },
+    Write { path: String, source: io::Error },
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalcError::Syntax(msg) => write!(f, "syntax error: {}", msg),
+            CalcError::UnknownMatrix(name) => write!(f, "unknown matrix '{}'", name),
+            CalcError::DimensionMismatch(msg) => write!(f, "dimension mismatch: {}", msg),
+            CalcError::Overflow => write!(f, "arithmetic overflow"),
+            CalcError::Read { path, source } => write!(f, "cannot read '{}': {}", path, source),
+            CalcError::Write { path, source } => write!(f, "cannot write '{}': {}", path, source),
+        }
+    }
+}
+
+impl Error for CalcError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CalcError::Read { source, .. } => Some(source),
+            CalcError::Write { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Quote,
+    LParen,
+    RParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Ident(name) => write!(f, "'{}'", name),
+            Token::Plus => write!(f, "'+'"),
+            Token::Minus => write!(f, "'-'"),
+            Token::Star => write!(f, "'*'"),
+            Token::Quote => write!(f, "'''"),
+            Token::LParen => write!(f, "'('"),
+            Token::RParen => write!(f, "')'"),
+        }
+    }
+}
+
+fn tokenize(src: &str) -> Result<Vec<Token>, CalcError> {
+    let mut tokens = Vec::new();
+    let mut chars = src.char_indices().peekable();
+    while let Some(&(pos, ch)) = chars.peek() {
+        match ch {
+            c if c.is_whitespace() => { chars.next(); }
+            '+' => { chars.next(); tokens.push(Token::Plus); }
+            '-' => { chars.next(); tokens.push(Token::Minus); }
+            '*' => { chars.next(); tokens.push(Token::Star); }
+            '\'' => { chars.next(); tokens.push(Token::Quote); }
+            '(' => { chars.next(); tokens.push(Token::LParen); }
+            ')' => { chars.next(); tokens.push(Token::RParen); }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut name = String::new();
+                while let Some(&(_, c)) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_') { break; }
+                    name.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Ident(name));
+            }
+            c => return Err(CalcError::Syntax(format!("unexpected character '{}' at column {}", c, pos + 1))),
+        }
+    }
+    Ok(tokens)
+}
+
+// 语法树; Temp 只在求值时出现, 指向已算好的转置结果
+enum Ast {
+    Var(String),
+    Temp(usize),
+    Add(Box<Ast>, Box<Ast>),
+    Sub(Box<Ast>, Box<Ast>),
+    Mul(Box<Ast>, Box<Ast>),
+    Neg(Box<Ast>),
+    Transpose(Box<Ast>),
+}
+
+// expr    := term (('+' | '-') term)*
+// term    := unary ('*' unary)*
+// unary   := '-' unary | postfix
+// postfix := primary '\''*
+// primary := IDENT | '(' expr ')'
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expr(&mut self) -> Result<Ast, CalcError> {
+        let mut lhs = self.term()?;
+        loop {
+            if self.eat(&Token::Plus) {
+                lhs = Ast::Add(Box::new(lhs), Box::new(self.term()?));
+            } else if self.eat(&Token::Minus) {
+                lhs = Ast::Sub(Box::new(lhs), Box::new(self.term()?));
+            } else {
+                return Ok(lhs);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Ast, CalcError> {
+        let mut lhs = self.unary()?;
+        while self.eat(&Token::Star) {
+            lhs = Ast::Mul(Box::new(lhs), Box::new(self.unary()?));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Ast, CalcError> {
+        if self.eat(&Token::Minus) {
+            return Ok(Ast::Neg(Box::new(self.unary()?)));
+        }
+        let mut operand = self.primary()?;
+        while self.eat(&Token::Quote) {
+            operand = Ast::Transpose(Box::new(operand));
+        }
+        Ok(operand)
+    }
+
+    fn primary(&mut self) -> Result<Ast, CalcError> {
+        match self.tokens.get(self.pos).cloned() {
+            Some(Token::Ident(name)) => {
+                self.pos += 1;
+                Ok(Ast::Var(name))
+            }
+            Some(Token::LParen) => {
+                self.pos += 1;
+                let inner = self.expr()?;
+                if !self.eat(&Token::RParen) {
+                    return Err(CalcError::Syntax("expected ')'".to_string()));
+                }
+                Ok(inner)
+            }
+            Some(token) => Err(CalcError::Syntax(format!("unexpected {}", token))),
+            None => Err(CalcError::Syntax("unexpected end of expression".to_string())),
+        }
+    }
+}
+
+fn parse_expr(src: &str) -> Result<Ast, CalcError> {
+    let mut parser = Parser { tokens: tokenize(src)?, pos: 0 };
+    let ast = parser.expr()?;
+    if let Some(token) = parser.peek() {
+        return Err(CalcError::Syntax(format!("unexpected {} after expression", token)));
+    }
+    Ok(ast)
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_') && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+// 变量名必须能在表达式里引用, 规则与词法分析中的 Ident 相同
+fn check_identifier(name: &str) -> Result<(), CalcError> {
+    if is_identifier(name) { Ok(()) } else { Err(CalcError::Syntax(format!("invalid variable name '{}'", name))) }
+}
+
+// 带名字的矩阵变量表, 按行执行赋值、load、save 语句
+#[derive(Default)]
+pub struct Session {
+    vars: BTreeMap<String, SparseMatrix>,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Session::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SparseMatrix> {
+        self.vars.get(name)
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut SparseMatrix> {
+        self.vars.get_mut(name)
+    }
+
+    pub fn insert(&mut self, name: &str, matrix: SparseMatrix) {
+        self.vars.insert(name.to_string(), matrix);
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.vars.keys().map(String::as_str)
+    }
+
+    pub fn load(&mut self, name: &str, path: &str, format: Option<Format>) -> Result<(), CalcError> {
+        check_identifier(name)?;
+        let format = format.unwrap_or_else(|| Format::from_path(path));
+        let matrix = SparseMatrix::load(path, format)
+            .map_err(|source| CalcError::Read { path: path.to_string(), source })?;
+        self.insert(name, matrix);
+        Ok(())
+    }
+
+    pub fn save(&self, name: &str, path: &str, format: Option<Format>) -> Result<(), CalcError> {
+        check_identifier(name)?;
+        let matrix = self.get(name).ok_or_else(|| CalcError::UnknownMatrix(name.to_string()))?;
+        let format = format.unwrap_or_else(|| Format::from_path(path));
+        matrix.save(path, format)
+            .map_err(|source| CalcError::Write { path: path.to_string(), source })
+    }
+
+    fn dims(&self, ast: &Ast, temps: &[SparseMatrix]) -> Result<(usize, usize), CalcError> {
+        match ast {
+            Ast::Var(name) => self.get(name).map(|m| m.dims()).ok_or_else(|| CalcError::UnknownMatrix(name.clone())),
+            Ast::Temp(i) => Ok(temps[*i].dims()),
+            Ast::Add(l, r) | Ast::Sub(l, r) => {
+                let (a, b) = (self.dims(l, temps)?, self.dims(r, temps)?);
+                if a != b {
+                    return Err(CalcError::DimensionMismatch(format!("cannot add {}x{} and {}x{}", a.0, a.1, b.0, b.1)));
+                }
+                Ok(a)
+            }
+            Ast::Mul(l, r) => {
+                let (a, b) = (self.dims(l, temps)?, self.dims(r, temps)?);
+                if a.1 != b.0 {
+                    return Err(CalcError::DimensionMismatch(format!("cannot multiply {}x{} by {}x{}", a.0, a.1, b.0, b.1)));
+                }
+                Ok((a.0, b.1))
+            }
+            Ast::Neg(e) => self.dims(e, temps),
+            Ast::Transpose(e) => self.dims(e, temps).map(|(r, c)| (c, r)),
+        }
+    }
+
+    // 转置没有对应的惰性运算, 先算出被转置的子式再转置, 结果放进 temps
+    fn lower(&self, ast: Ast, temps: &mut Vec<SparseMatrix>) -> Result<Ast, CalcError> {
+        Ok(match ast {
+            Ast::Transpose(inner) => {
+                let inner = self.lower(*inner, temps)?;
+                let m = self.build(&inner, temps).checked_eval().ok_or(CalcError::Overflow)?;
+                temps.push(m.transpose());
+                Ast::Temp(temps.len() - 1)
+            }
+            Ast::Add(l, r) => Ast::Add(Box::new(self.lower(*l, temps)?), Box::new(self.lower(*r, temps)?)),
+            Ast::Sub(l, r) => Ast::Sub(Box::new(self.lower(*l, temps)?), Box::new(self.lower(*r, temps)?)),
+            Ast::Mul(l, r) => Ast::Mul(Box::new(self.lower(*l, temps)?), Box::new(self.lower(*r, temps)?)),
+            Ast::Neg(e) => Ast::Neg(Box::new(self.lower(*e, temps)?)),
+            leaf => leaf,
+        })
+    }
+
+    fn build<'a>(&'a self, ast: &Ast, temps: &'a [SparseMatrix]) -> Expr<'a> {
+        match ast {
+            Ast::Var(name) => Expr::Matrix(&self.vars[name]),
+            Ast::Temp(i) => Expr::Matrix(&temps[*i]),
+            Ast::Add(l, r) => self.build(l, temps) + self.build(r, temps),
+            Ast::Sub(l, r) => self.build(l, temps) - self.build(r, temps),
+            Ast::Mul(l, r) => self.build(l, temps) * self.build(r, temps),
+            Ast::Neg(e) => -self.build(e, temps),
+            Ast::Transpose(_) => unreachable!("transposes are lowered before building"),
+        }
+    }
+
+    pub fn eval(&self, src: &str) -> Result<SparseMatrix, CalcError> {
+        let ast = parse_expr(src)?;
+        self.dims(&ast, &[])?;
+        let mut temps = Vec::new();
+        let ast = self.lower(ast, &mut temps)?;
+        self.build(&ast, &temps).checked_eval().ok_or(CalcError::Overflow)
+    }
+
+    // 执行一条语句, 返回被赋值 (或载入) 的变量名:
+    //   NAME = expr | load NAME PATH [FORMAT] | save NAME PATH [FORMAT] | expr (结果存入 ans)
+    pub fn execute(&mut self, stmt: &str) -> Result<Option<String>, CalcError> {
+        let stmt = stmt.trim();
+        if stmt.is_empty() || stmt.starts_with('#') {
+            return Ok(None);
+        }
+
+        let words: Vec<&str> = stmt.split_whitespace().collect();
+        if matches!(words[0], "load" | "save") {
+            let (name, path) = match (words.get(1), words.get(2)) {
+                (Some(name), Some(path)) if words.len() <= 4 => (*name, *path),
+                _ => return Err(CalcError::Syntax(format!("usage: {} NAME PATH [FORMAT]", words[0]))),
+            };
+            let format = words.get(3).map(|f| f.parse::<Format>()).transpose().map_err(CalcError::Syntax)?;
+            return if words[0] == "load" {
+                self.load(name, path, format).map(|_| Some(name.to_string()))
+            } else {
+                self.save(name, path, format).map(|_| None)
+            };
+        }
+
+        let (name, src) = match stmt.split_once('=') {
+            Some((lhs, rhs)) => {
+                let lhs = lhs.trim();
+                check_identifier(lhs)?;
+                (lhs, rhs)
+            }
+            None => ("ans", stmt),
+        };
+        let value = self.eval(src)?;
+        self.insert(name, value);
+        Ok(Some(name.to_string()))
+    }
+}
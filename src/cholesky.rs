@@ -0,0 +1,289 @@
+use std::fmt;
+
+use crate::{Scalar, SparseMatrix};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CholeskyError {
+    NotSquare { rows: usize, cols: usize },
+    // A(row, col) != A(col, row)
+    NotSymmetric { row: usize, col: usize },
+    // 第 col 列的主元 (消去后的对角元) 不是正数
+    NotPositiveDefinite { col: usize, pivot: f64 },
+}
+
+impl fmt::Display for CholeskyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CholeskyError::NotSquare { rows, cols } => write!(f, "cannot factorize a {}x{} matrix: not square", rows, cols),
+            CholeskyError::NotSymmetric { row, col } => {
+                write!(f, "matrix is not symmetric: entries ({}, {}) and ({}, {}) differ", row, col, col, row)
+            }
+            CholeskyError::NotPositiveDefinite { col, pivot } => {
+                write!(f, "matrix is not positive definite: pivot {} in column {}", pivot, col)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CholeskyError {}
+
+// 符号分析结果: 消去树与 L 每列的非零元个数 (含对角元), 下标 j - 1 对应第 j 列
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolicCholesky {
+    pub parent: Vec<Option<usize>>,
+    pub col_counts: Vec<usize>,
+}
+
+impl SymbolicCholesky {
+    pub fn nnz(&self) -> usize {
+        self.col_counts.iter().sum()
+    }
+}
+
+// A = L·L^T, L 以下三角 SparseMatrix<f64> 存放
+pub struct Cholesky {
+    symbolic: SymbolicCholesky,
+    l: SparseMatrix<f64>,
+}
+
+impl Cholesky {
+    pub fn factor(&self) -> &SparseMatrix<f64> {
+        &self.l
+    }
+
+    pub fn symbolic(&self) -> &SymbolicCholesky {
+        &self.symbolic
+    }
+
+    // 解 A x = b: 先 L y = b, 再 L^T x = y
+    pub fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let y = self.l.solve_lower(b);
+        self.l.solve_lower_transpose(&y)
+    }
+}
+
+// 第 k 行 L 的非零模式: 从 A(i, k) (i < k) 沿消去树向上走到已标记的结点;
+// 返回的顺序保证子结点排在祖先之前, 下标从 0 开始
+fn ereach(k: usize, upper: &[usize], parent: &[Option<usize>], mark: &mut [usize], stack: &mut Vec<usize>) -> Vec<usize> {
+    stack.clear();
+    mark[k] = k;
+    for &i in upper {
+        let start = stack.len();
+        let mut i = i;
+        while mark[i] != k {
+            mark[i] = k;
+            stack.push(i);
+            match parent[i] {
+                Some(p) => i = p,
+                None => break,
+            }
+        }
+        stack[start..].reverse();
+    }
+    stack.iter().rev().copied().collect()
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    fn check_square_for_cholesky(&self) -> Result<usize, CholeskyError> {
+        let (rows, cols) = self.dims();
+        if rows != cols {
+            return Err(CholeskyError::NotSquare { rows, cols });
+        }
+        Ok(rows)
+    }
+
+    // 每列严格上三角部分的行号 (0 起始), 即 A(i, k), i < k
+    fn upper_pattern(&self) -> Vec<Vec<usize>> {
+        let (_, cols) = self.dims();
+        let mut upper = vec![Vec::new(); cols];
+        for (r, c, _) in self.col_major_triplets() {
+            if r < c {
+                upper[c - 1].push(r - 1);
+            }
+        }
+        upper
+    }
+
+    // 消去树 (Liu 算法, 带路径压缩), 只用到上三角部分的结构; parent[j - 1] 为第 j 列的父结点
+    pub fn elimination_tree(&self) -> Vec<Option<usize>> {
+        let n = match self.check_square_for_cholesky() {
+            Ok(n) => n,
+            Err(e) => panic!("{}", e),
+        };
+        let mut parent: Vec<Option<usize>> = vec![None; n];
+        let mut ancestor: Vec<Option<usize>> = vec![None; n];
+        for (k, rows) in self.upper_pattern().iter().enumerate() {
+            for &i in rows {
+                let mut i = i;
+                loop {
+                    let next = ancestor[i].replace(k);
+                    match next {
+                        None => {
+                            parent[i] = Some(k);
+                            break;
+                        }
+                        Some(a) if a == k => break,
+                        Some(a) => i = a,
+                    }
+                }
+            }
+        }
+        parent.into_iter().map(|p| p.map(|p| p + 1)).collect()
+    }
+
+    pub fn symbolic_cholesky(&self) -> SymbolicCholesky {
+        let parent = self.elimination_tree();
+        let n = parent.len();
+        let parent0: Vec<Option<usize>> = parent.iter().map(|p| p.map(|p| p - 1)).collect();
+        let mut col_counts = vec![1; n];
+        let mut mark = vec![usize::MAX; n];
+        let mut stack = Vec::new();
+        for (k, rows) in self.upper_pattern().iter().enumerate() {
+            for j in ereach(k, rows, &parent0, &mut mark, &mut stack) {
+                col_counts[j] += 1;
+            }
+        }
+        SymbolicCholesky { parent, col_counts }
+    }
+}
+
+impl SparseMatrix<f64> {
+    fn check_symmetric(&self) -> Result<(), CholeskyError> {
+        let entries = self.triplets();
+        let transposed: Vec<(usize, usize, f64)> = self.col_major_triplets()
+            .into_iter()
+            .map(|(r, c, v)| (c, r, v))
+            .collect();
+        let mismatch = entries.iter().zip(&transposed).find(|(a, b)| a != b);
+        if let Some((a, b)) = mismatch {
+            let (row, col) = (a.0, a.1).min((b.0, b.1));
+            return Err(CholeskyError::NotSymmetric { row, col });
+        }
+        Ok(())
+    }
+
+    // 按行的 up-looking 算法: 第 k 行由 L(0..k, 0..k)·x = A(0..k, k) 解出, 模式由 ereach 给出
+    pub fn cholesky(&self) -> Result<Cholesky, CholeskyError> {
+        let n = self.check_square_for_cholesky()?;
+        self.check_symmetric()?;
+        let symbolic = self.symbolic_cholesky();
+        let parent0: Vec<Option<usize>> = symbolic.parent.iter().map(|p| p.map(|p| p - 1)).collect();
+
+        // 按列存放 L, 每列第一个元素是对角元
+        let mut l_cols: Vec<Vec<(usize, f64)>> = symbolic.col_counts.iter().map(|&c| Vec::with_capacity(c)).collect();
+        let mut x = vec![0.0; n];
+        let mut mark = vec![usize::MAX; n];
+        let mut stack = Vec::new();
+        let columns = self.col_lines();
+
+        for k in 0..n {
+            // 把 A(0..k, k) 散布到 x 中, d 取 A(k, k)
+            let mut upper = Vec::new();
+            let mut d = 0.0;
+            for &(r, v) in &columns[k + 1] {
+                if r <= k {
+                    x[r - 1] = v;
+                    upper.push(r - 1);
+                } else if r == k + 1 {
+                    d = v;
+                }
+            }
+            for j in ereach(k, &upper, &parent0, &mut mark, &mut stack) {
+                let l_kj = x[j] / l_cols[j][0].1;
+                x[j] = 0.0;
+                for &(i, l_ij) in &l_cols[j][1..] {
+                    x[i] -= l_ij * l_kj;
+                }
+                d -= l_kj * l_kj;
+                l_cols[j].push((k, l_kj));
+            }
+            if d.is_nan() || d <= 0.0 {
+                return Err(CholeskyError::NotPositiveDefinite { col: k + 1, pivot: d });
+            }
+            l_cols[k].push((k, d.sqrt()));
+        }
+
+        let triplets = l_cols.iter().enumerate()
+            .flat_map(|(j, col)| col.iter().map(move |&(i, v)| (i + 1, j + 1, v)))
+            .filter(|&(_, _, v)| v != 0.0)
+            .collect();
+        let l = SparseMatrix::from_triplets(n, n, triplets);
+        Ok(Cholesky { symbolic, l })
+    }
+
+    fn check_triangular_solve(&self, b: &[f64]) {
+        let (rows, cols) = self.dims();
+        if rows != cols || b.len() != rows {
+            panic!("Dimensions mismatch for triangular solve");
+        }
+    }
+
+    //前代: 解 L x = b, self 为下三角
+    pub fn solve_lower(&self, b: &[f64]) -> Vec<f64> {
+        self.check_triangular_solve(b);
+        let mut x = b.to_vec();
+        for (i, line) in self.row_lines().iter().enumerate().skip(1) {
+            let mut diag = 0.0;
+            for &(j, v) in line {
+                if j < i {
+                    x[i - 1] -= v * x[j - 1];
+                } else if j == i {
+                    diag = v;
+                } else {
+                    panic!("Matrix is not lower triangular: entry ({}, {})", i, j);
+                }
+            }
+            if diag == 0.0 {
+                panic!("Zero diagonal at row {} in triangular solve", i);
+            }
+            x[i - 1] /= diag;
+        }
+        x
+    }
+
+    //回代: 解 U x = b, self 为上三角
+    pub fn solve_upper(&self, b: &[f64]) -> Vec<f64> {
+        self.check_triangular_solve(b);
+        let mut x = b.to_vec();
+        for (i, line) in self.row_lines().iter().enumerate().skip(1).rev() {
+            let mut diag = 0.0;
+            for &(j, v) in line {
+                if j > i {
+                    x[i - 1] -= v * x[j - 1];
+                } else if j == i {
+                    diag = v;
+                } else {
+                    panic!("Matrix is not upper triangular: entry ({}, {})", i, j);
+                }
+            }
+            if diag == 0.0 {
+                panic!("Zero diagonal at row {} in triangular solve", i);
+            }
+            x[i - 1] /= diag;
+        }
+        x
+    }
+
+    // 回代: 解 L^T x = b, 沿 L 的列访问, 不必显式转置
+    pub fn solve_lower_transpose(&self, b: &[f64]) -> Vec<f64> {
+        self.check_triangular_solve(b);
+        let mut x = b.to_vec();
+        for (j, line) in self.col_lines().iter().enumerate().skip(1).rev() {
+            let mut diag = 0.0;
+            for &(i, v) in line {
+                if i > j {
+                    x[j - 1] -= v * x[i - 1];
+                } else if i == j {
+                    diag = v;
+                } else {
+                    panic!("Matrix is not lower triangular: entry ({}, {})", i, j);
+                }
+            }
+            if diag == 0.0 {
+                panic!("Zero diagonal at row {} in triangular solve", j);
+            }
+            x[j - 1] /= diag;
+        }
+        x
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::{Scalar, SparseMatrix};
+
+// approx_eq 找到的第一处差异; 缺失的元素按 0 计
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatrixMismatch<T = i32> {
+    Dims { left: (usize, usize), right: (usize, usize) },
+    Value { row: usize, col: usize, left: T, right: T },
+}
+
+impl<T: Scalar> fmt::Display for MatrixMismatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixMismatch::Dims { left, right } => {
+                write!(f, "dimensions differ: {}x{} vs {}x{}", left.0, left.1, right.0, right.1)
+            }
+            MatrixMismatch::Value { row, col, left, right } => {
+                write!(f, "entries at ({}, {}) differ: {} vs {}", row, col, left, right)
+            }
+        }
+    }
+}
+
+impl<T: Scalar> std::error::Error for MatrixMismatch<T> {}
+
+// 深拷贝: 按存储的结点重新串起两条循环链表, 保留存储方式
+impl<T: Scalar> Clone for SparseMatrix<T> {
+    fn clone(&self) -> Self {
+        let (rows, cols) = self.dims();
+        let mut copy = SparseMatrix::from_row_major(rows, cols, self.stored_triplets());
+        copy.symmetric = self.symmetric;
+        copy
+    }
+}
+
+// 按矩阵的值比较: 维数、非零模式和数值都相同即相等, 与存储方式无关
+impl<T: Scalar> PartialEq for SparseMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dims() == other.dims() && self.triplets() == other.triplets()
+    }
+}
+
+impl<T: Scalar + Eq> Eq for SparseMatrix<T> {}
+
+impl<T: Scalar + Hash> Hash for SparseMatrix<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.dims().hash(state);
+        self.triplets().hash(state);
+    }
+}
+
+impl<T: Scalar> fmt::Debug for SparseMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rows, cols) = self.dims();
+        f.debug_struct("SparseMatrix")
+            .field("rows", &rows)
+            .field("cols", &cols)
+            .field("symmetric", &self.symmetric)
+            .field("entries", &self.triplets())
+            .finish()
+    }
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    // 逐元素比较 |a - b| <= abs_tol + rel_tol * max(|a|, |b|), 按行主序返回第一处不满足的位置
+    pub fn approx_eq(&self, other: &SparseMatrix<T>, abs_tol: f64, rel_tol: f64) -> Result<(), MatrixMismatch<T>> {
+        if self.dims() != other.dims() {
+            return Err(MatrixMismatch::Dims { left: self.dims(), right: other.dims() });
+        }
+        let close = |a: T, b: T| {
+            let (x, y) = (a.to_f64(), b.to_f64());
+            (x - y).abs() <= abs_tol + rel_tol * x.abs().max(y.abs())
+        };
+
+        let (a, b) = (self.triplets(), other.triplets());
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() || j < b.len() {
+            let key_a = a.get(i).map_or((usize::MAX, usize::MAX), |e| (e.0, e.1));
+            let key_b = b.get(j).map_or((usize::MAX, usize::MAX), |e| (e.0, e.1));
+            let (key, left, right) = if key_a < key_b {
+                i += 1;
+                (key_a, a[i - 1].2, T::zero())
+            } else if key_b < key_a {
+                j += 1;
+                (key_b, T::zero(), b[j - 1].2)
+            } else {
+                i += 1;
+                j += 1;
+                (key_a, a[i - 1].2, b[j - 1].2)
+            };
+            if !close(left, right) {
+                return Err(MatrixMismatch::Value { row: key.0, col: key.1, left, right });
+            }
+        }
+        Ok(())
+    }
+}
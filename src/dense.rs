@@ -0,0 +1,122 @@
+use std::io::Write;
+
+use crate::{write_grid, Scalar, SparseMatrix};
+
+// 按行主序存放的稠密矩阵, 下标与 SparseMatrix 一样从 1 开始
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseMatrix<T = i32> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Scalar> DenseMatrix<T> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        DenseMatrix { rows, cols, data: vec![T::zero(); rows * cols] }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|r| r.len() != cols) {
+            panic!("All rows of a dense matrix must have the same length");
+        }
+        DenseMatrix { rows: rows.len(), cols, data: rows.concat() }
+    }
+
+    pub fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        if row == 0 || row > self.rows || col == 0 || col > self.cols {
+            panic!("Position ({}, {}) out of bounds for {}x{} matrix", row, col, self.rows, self.cols);
+        }
+        (row - 1) * self.cols + (col - 1)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self.data[self.index(row, col)]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        let i = self.index(row, col);
+        self.data[i] = value;
+    }
+
+    // 第 i 行 (1 起始)
+    pub fn row(&self, i: usize) -> &[T] {
+        if i == 0 || i > self.rows {
+            panic!("Row index {} out of bounds (valid indices are 1..={})", i, self.rows);
+        }
+        &self.data[(i - 1) * self.cols..i * self.cols]
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn print(&self) {
+        self.write_dense(std::io::stdout().lock()).expect("failed to write matrix to stdout");
+    }
+
+    // 与 SparseMatrix::write_dense 相同的输出格式
+    pub fn write_dense<W: Write>(&self, w: W) -> std::io::Result<()> {
+        write_grid(w, self.rows, self.cols, |r, c| self.data[(r - 1) * self.cols + (c - 1)])
+    }
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    pub fn to_dense(&self) -> DenseMatrix<T> {
+        let (rows, cols) = self.dims();
+        let mut dense = DenseMatrix::new(rows, cols);
+        for (r, c, v) in self.triplets() {
+            dense.data[(r - 1) * cols + (c - 1)] += v;
+        }
+        dense
+    }
+
+    pub fn from_dense(dense: &DenseMatrix<T>) -> SparseMatrix<T> {
+        SparseMatrix::from_dense_with_tolerance(dense, T::zero())
+    }
+
+    // 绝对值不超过 drop_tol 的元素不存储
+    pub fn from_dense_with_tolerance(dense: &DenseMatrix<T>, drop_tol: T) -> SparseMatrix<T> {
+        let (rows, cols) = dense.dims();
+        let entries = (1..=rows).flat_map(|r| {
+            dense.row(r).iter().enumerate()
+                .filter(move |&(_, v)| v.abs() > drop_tol.abs())
+                .map(move |(c, &v)| (r, c + 1, v))
+        });
+        SparseMatrix::from_row_major(rows, cols, entries)
+    }
+
+    pub fn add_dense(&self, other: &DenseMatrix<T>) -> DenseMatrix<T> {
+        if self.dims() != other.dims() {
+            panic!("Dimensions mismatch for addition");
+        }
+        let mut result = other.clone();
+        let cols = result.cols;
+        for (r, c, v) in self.triplets() {
+            result.data[(r - 1) * cols + (c - 1)] += v;
+        }
+        result
+    }
+
+    // 稀疏 x 稠密: 结果第 i 行 = sum A(i, k) * B 的第 k 行
+    pub fn multiply_dense(&self, other: &DenseMatrix<T>) -> DenseMatrix<T> {
+        let (r_a, c_a) = self.dims();
+        let (r_b, c_b) = other.dims();
+        if c_a != r_b {
+            panic!("Dimensions mismatch for multiplication");
+        }
+        let mut result = DenseMatrix::new(r_a, c_b);
+        for (r, k, v) in self.triplets() {
+            let src = other.row(k);
+            let dst = &mut result.data[(r - 1) * c_b..r * c_b];
+            for (d, &b) in dst.iter_mut().zip(src) {
+                *d += v * b;
+            }
+        }
+        result
+    }
+}
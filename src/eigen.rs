@@ -0,0 +1,399 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::SparseMatrix;
+
+// 迭代终止条件: 残差 ||A x - λ x|| 不超过 tol 乘以 |λ| (Lanczos 用 Ritz 值的最大模估计 ||A||)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EigenOptions {
+    pub max_iter: usize,
+    pub tol: f64,
+}
+
+impl Default for EigenOptions {
+    fn default() -> Self {
+        EigenOptions { max_iter: 1000, tol: 1e-10 }
+    }
+}
+
+// 单个特征对; history[k] 为第 k + 1 次迭代后的残差
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigenResult {
+    pub value: f64,
+    pub vector: Vec<f64>,
+    pub iterations: usize,
+    pub residual: f64,
+    pub converged: bool,
+    pub history: Vec<f64>,
+}
+
+// Lanczos 需要的那一端谱
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Which {
+    LargestMagnitude,
+    LargestAlgebraic,
+    SmallestAlgebraic,
+}
+
+// k 个 Ritz 对, 按 Which 的顺序排列; history[k] 为第 k + 1 步时所求 Ritz 对的最大残差估计
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanczosResult {
+    pub values: Vec<f64>,
+    pub vectors: Vec<Vec<f64>>,
+    pub residuals: Vec<f64>,
+    pub iterations: usize,
+    pub converged: bool,
+    pub history: Vec<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EigenError {
+    NotSquare { rows: usize, cols: usize },
+    // A - shift·I 在第 col 列消去时找不到非零主元, 即 shift 恰好是一个特征值
+    SingularShift { shift: f64, col: usize },
+}
+
+impl fmt::Display for EigenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EigenError::NotSquare { rows, cols } => write!(f, "cannot compute eigenvalues of a {}x{} matrix: not square", rows, cols),
+            EigenError::SingularShift { shift, col } => {
+                write!(f, "A - {}·I is singular: no pivot in column {} (the shift is an eigenvalue)", shift, col)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EigenError {}
+
+// 带部分主元的稀疏 LU 分解 P·A = L·U, 不要求对称或正定, 供带位移的反迭代解方程;
+// 下标从 0 开始, perm[k] 为第 k 步选作主元的原始行号
+struct SparseLu {
+    perm: Vec<usize>,
+    // 第 k 步的消去乘子 (原始行号, l_ik)
+    l: Vec<Vec<(usize, f64)>>,
+    // 第 k 步的主元行, 只含第 k 列及其右侧
+    u: Vec<BTreeMap<usize, f64>>,
+}
+
+impl SparseLu {
+    // 右视消去: rows 为当前各行, col_rows[j] 为尚未选作主元且第 j 列非零的行
+    fn factor(n: usize, entries: impl IntoIterator<Item = (usize, usize, f64)>) -> Result<SparseLu, usize> {
+        let mut rows: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); n];
+        let mut col_rows: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+        for (r, c, v) in entries {
+            if v != 0.0 {
+                rows[r].insert(c, v);
+                col_rows[c].insert(r);
+            }
+        }
+        let mut lu = SparseLu { perm: Vec::with_capacity(n), l: Vec::with_capacity(n), u: Vec::with_capacity(n) };
+        for k in 0..n {
+            // 取第 k 列模最大的元素为主元, 并列时取行号小的
+            let p = col_rows[k].iter().copied()
+                .fold(None, |best: Option<usize>, i| match best {
+                    Some(b) if rows[b][&k].abs() >= rows[i][&k].abs() => Some(b),
+                    _ => Some(i),
+                })
+                .ok_or(k)?;
+            let pivot_row = std::mem::take(&mut rows[p]);
+            for &j in pivot_row.keys() {
+                col_rows[j].remove(&p);
+            }
+            let pivot = pivot_row[&k];
+            let mut multipliers = Vec::new();
+            for i in std::mem::take(&mut col_rows[k]) {
+                let l_ik = rows[i].remove(&k).unwrap_or(0.0) / pivot;
+                for (&j, &u_kj) in pivot_row.range(k + 1..) {
+                    let v = rows[i].get(&j).copied().unwrap_or(0.0) - l_ik * u_kj;
+                    if v == 0.0 {
+                        rows[i].remove(&j);
+                        col_rows[j].remove(&i);
+                    } else {
+                        rows[i].insert(j, v);
+                        col_rows[j].insert(i);
+                    }
+                }
+                multipliers.push((i, l_ik));
+            }
+            lu.perm.push(p);
+            lu.l.push(multipliers);
+            lu.u.push(pivot_row);
+        }
+        Ok(lu)
+    }
+
+    // 解 A x = b: 按消去顺序对 b 做同样的行变换, 再对 U 回代
+    fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let mut y = b.to_vec();
+        for (&p, multipliers) in self.perm.iter().zip(&self.l) {
+            for &(i, l_ik) in multipliers {
+                y[i] -= l_ik * y[p];
+            }
+        }
+        let n = self.perm.len();
+        let mut x = vec![0.0; n];
+        for k in (0..n).rev() {
+            let row = &self.u[k];
+            let sum: f64 = row.range(k + 1..).map(|(&j, &u_kj)| u_kj * x[j]).sum();
+            x[k] = (y[self.perm[k]] - sum) / row[&k];
+        }
+        x
+    }
+}
+
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
+
+fn norm(x: &[f64]) -> f64 {
+    dot(x, x).sqrt()
+}
+
+fn scale(x: &mut [f64], a: f64) {
+    x.iter_mut().for_each(|v| *v *= a);
+}
+
+// 确定性的起始向量, 各分量都不为零, 避免恰好与所求特征向量正交
+fn start_vector(n: usize) -> Vec<f64> {
+    let mut x: Vec<f64> = (1..=n).map(|i| 0.5 + (i as f64 * 0.618_033_988_749_895).fract()).collect();
+    let len = norm(&x);
+    if len > 0.0 {
+        scale(&mut x, 1.0 / len);
+    }
+    x
+}
+
+// 对称三对角矩阵的隐式 QL 迭代: d 为对角元, e[i] 为 (i, i + 1) 处的次对角元;
+// 结束时 d 为特征值, rows 中每一行同步施加旋转 (传入单位阵的若干行即得特征向量矩阵的对应行)
+fn tridiagonal_ql(d: &mut [f64], e: &mut [f64], rows: &mut [Vec<f64>]) {
+    let n = d.len();
+    for l in 0..n {
+        let mut iter = 0;
+        loop {
+            let mut m = l;
+            while m + 1 < n {
+                let dd = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON * dd {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            iter += 1;
+            if iter > 60 {
+                break;
+            }
+            let mut g = (d[l + 1] - d[l]) / (2.0 * e[l]);
+            let mut r = g.hypot(1.0);
+            g = d[m] - d[l] + e[l] / (g + r.copysign(g));
+            let (mut s, mut c, mut p) = (1.0, 1.0, 0.0);
+            let mut deflated = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == 0.0 {
+                    d[i + 1] -= p;
+                    e[m] = 0.0;
+                    deflated = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + 2.0 * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+                for row in rows.iter_mut() {
+                    let f = row[i + 1];
+                    row[i + 1] = s * row[i] + c * f;
+                    row[i] = c * row[i] - s * f;
+                }
+            }
+            if deflated {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0.0;
+        }
+    }
+}
+
+// 按 which 选出 k 个 Ritz 值的下标
+fn select(values: &[f64], k: usize, which: Which) -> Vec<usize> {
+    let mut idx: Vec<usize> = (0..values.len()).collect();
+    match which {
+        Which::LargestMagnitude => idx.sort_by(|&a, &b| values[b].abs().total_cmp(&values[a].abs())),
+        Which::LargestAlgebraic => idx.sort_by(|&a, &b| values[b].total_cmp(&values[a])),
+        Which::SmallestAlgebraic => idx.sort_by(|&a, &b| values[a].total_cmp(&values[b])),
+    }
+    idx.truncate(k);
+    idx
+}
+
+impl SparseMatrix<f64> {
+    fn check_square_for_eigen(&self) -> usize {
+        let (rows, cols) = self.dims();
+        if rows != cols {
+            panic!("Eigenvalue methods require a square matrix");
+        }
+        rows
+    }
+
+    // 每一步由 next(x) 给出下一个方向, 特征值取 Rayleigh 商 x·Ax
+    fn iterate<F>(&self, opts: &EigenOptions, mut next: F) -> EigenResult
+    where
+        F: FnMut(&[f64]) -> Vec<f64>,
+    {
+        let n = self.check_square_for_eigen();
+        let mut x = start_vector(n);
+        let mut result = EigenResult {
+            value: 0.0,
+            vector: x.clone(),
+            iterations: 0,
+            residual: 0.0,
+            converged: n == 0,
+            history: Vec::new(),
+        };
+        while !result.converged && result.iterations < opts.max_iter {
+            let mut y = next(&x);
+            let len = norm(&y);
+            if len == 0.0 {
+                // x 落在零空间里: 特征值为 0
+                result.value = 0.0;
+                result.vector = x.clone();
+                result.residual = 0.0;
+                result.iterations += 1;
+                result.history.push(0.0);
+                result.converged = true;
+                break;
+            }
+            scale(&mut y, 1.0 / len);
+            x = y;
+
+            let ax = self.mul_vec(&x);
+            let lambda = dot(&x, &ax);
+            let residual = norm(&ax.iter().zip(&x).map(|(a, v)| a - lambda * v).collect::<Vec<_>>());
+            result.value = lambda;
+            result.vector = x.clone();
+            result.residual = residual;
+            result.iterations += 1;
+            result.history.push(residual);
+            result.converged = residual <= opts.tol * lambda.abs();
+        }
+        result
+    }
+
+    // 幂迭代: 收敛到模最大的特征值
+    pub fn power_iteration(&self, opts: &EigenOptions) -> EigenResult {
+        self.iterate(opts, |x| self.mul_vec(x))
+    }
+
+    // 反迭代: 每步解 (A - shift·I) y = x, 收敛到离 shift 最近的特征值;
+    // 方程组用带部分主元的稀疏 LU 求解, shift 可以落在谱的内部, A 也不必对称
+    pub fn inverse_iteration(&self, shift: f64, opts: &EigenOptions) -> Result<EigenResult, EigenError> {
+        let (rows, cols) = self.dims();
+        if rows != cols {
+            return Err(EigenError::NotSquare { rows, cols });
+        }
+        let diag = (1..=rows).map(|i| (i, i, -shift));
+        let entries = self.triplets().into_iter().chain(diag);
+        // 同一位置的值相加, 得到 A - shift·I 的三元组
+        let mut shifted: BTreeMap<(usize, usize), f64> = BTreeMap::new();
+        for (r, c, v) in entries {
+            *shifted.entry((r - 1, c - 1)).or_insert(0.0) += v;
+        }
+        let lu = SparseLu::factor(rows, shifted.into_iter().map(|((r, c), v)| (r, c, v)))
+            .map_err(|k| EigenError::SingularShift { shift, col: k + 1 })?;
+        Ok(self.iterate(opts, |x| lu.solve(x)))
+    }
+
+    // 带完全重正交化的 Lanczos 方法, 求对称矩阵的 k 个特征对; 最多 max_iter 步 (不超过矩阵阶数)
+    pub fn lanczos(&self, k: usize, which: Which, opts: &EigenOptions) -> LanczosResult {
+        let n = self.check_square_for_eigen();
+        if !self.is_symmetric() {
+            panic!("Lanczos requires a symmetric matrix");
+        }
+        if k > n {
+            panic!("Requested {} eigenpairs of a {}x{} matrix", k, n, n);
+        }
+        let steps = opts.max_iter.min(n);
+
+        let mut basis: Vec<Vec<f64>> = Vec::new();
+        let mut alpha: Vec<f64> = Vec::new();
+        let mut beta: Vec<f64> = Vec::new();
+        let mut history = Vec::new();
+        let mut q = start_vector(n);
+        let mut converged = k == 0;
+
+        while !converged && basis.len() < steps {
+            let mut w = self.mul_vec(&q);
+            let a = dot(&q, &w);
+            w.iter_mut().zip(&q).for_each(|(wi, qi)| *wi -= a * qi);
+            if let (Some(prev), Some(&b)) = (basis.last(), beta.last()) {
+                w.iter_mut().zip(prev).for_each(|(wi, pi)| *wi -= b * pi);
+            }
+            basis.push(q);
+            alpha.push(a);
+            // 完全重正交化, 防止 Ritz 值重复出现
+            for v in &basis {
+                let h = dot(v, &w);
+                w.iter_mut().zip(v).for_each(|(wi, vi)| *wi -= h * vi);
+            }
+            let b = norm(&w);
+
+            // 三对角矩阵 T 的特征值与特征向量最后一行: 残差估计为 |b · s_last|
+            let m = alpha.len();
+            let mut d = alpha.clone();
+            let mut e: Vec<f64> = beta.iter().copied().chain(std::iter::once(0.0)).collect();
+            let mut last = vec![vec![0.0; m]];
+            last[0][m - 1] = 1.0;
+            tridiagonal_ql(&mut d, &mut e, &mut last);
+            let anorm = d.iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+            let wanted = select(&d, k, which);
+            let worst = wanted.iter().map(|&i| (b * last[0][i]).abs()).fold(0.0, f64::max);
+            history.push(worst);
+
+            // b 为零说明 Krylov 子空间已不变, Ritz 对即精确特征对
+            let exhausted = b <= f64::EPSILON * anorm.max(1.0);
+            converged = wanted.len() == k && (worst <= opts.tol * anorm || exhausted);
+            if exhausted {
+                break;
+            }
+            beta.push(b);
+            q = w;
+            scale(&mut q, 1.0 / b);
+        }
+
+        // 由 Ritz 向量 x = Q·s 得到特征向量, 并计算真实残差
+        let m = alpha.len();
+        let mut d = alpha;
+        let mut e: Vec<f64> = beta.into_iter().take(m.saturating_sub(1)).chain(std::iter::once(0.0)).collect();
+        e.truncate(m);
+        let mut z: Vec<Vec<f64>> = (0..m).map(|i| (0..m).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect();
+        tridiagonal_ql(&mut d, &mut e, &mut z);
+        let wanted = select(&d, k, which);
+
+        let mut values = Vec::with_capacity(k);
+        let mut vectors = Vec::with_capacity(k);
+        let mut residuals = Vec::with_capacity(k);
+        for &i in &wanted {
+            let mut x = vec![0.0; n];
+            for (row, v) in z.iter().zip(&basis) {
+                x.iter_mut().zip(v).for_each(|(xi, vi)| *xi += row[i] * vi);
+            }
+            let ax = self.mul_vec(&x);
+            let residual = norm(&ax.iter().zip(&x).map(|(a, v)| a - d[i] * v).collect::<Vec<_>>());
+            values.push(d[i]);
+            vectors.push(x);
+            residuals.push(residual);
+        }
+        LanczosResult { values, vectors, residuals, iterations: m, converged, history }
+    }
+}
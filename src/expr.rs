@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::rc::Rc;
+
+use crate::{Scalar, SparseMatrix};
+
+type Rows<T> = Rc<Vec<Vec<(usize, T)>>>;
+
+// 惰性表达式: 运算符只搭建表达式树, eval() 时逐行一次算出结果, 中间不生成链表结点
+pub enum Expr<'a, T = i32> {
+    Matrix(&'a SparseMatrix<T>),
+    Add(Box<Expr<'a, T>>, Box<Expr<'a, T>>),
+    Sub(Box<Expr<'a, T>>, Box<Expr<'a, T>>),
+    Mul(Box<Expr<'a, T>>, Box<Expr<'a, T>>),
+    Neg(Box<Expr<'a, T>>),
+}
+
+// 编译后的求值计划, 叶子是各行的只读快照
+enum Plan<T> {
+    Rows(Rows<T>),
+    Add(Box<Plan<T>>, Box<Plan<T>>),
+    Sub(Box<Plan<T>>, Box<Plan<T>>),
+    // 右操作数需要按行随机访问, 因此总是快照或预先算好
+    Mul(Box<Plan<T>>, Rows<T>),
+    Neg(Box<Plan<T>>),
+}
+
+impl<'a, T: Scalar> Expr<'a, T> {
+    pub fn dims(&self) -> (usize, usize) {
+        match self {
+            Expr::Matrix(m) => m.dims(),
+            Expr::Add(l, _) | Expr::Sub(l, _) => l.dims(),
+            Expr::Mul(l, r) => (l.dims().0, r.dims().1),
+            Expr::Neg(e) => e.dims(),
+        }
+    }
+
+    fn sum(l: Expr<'a, T>, r: Expr<'a, T>, subtract: bool) -> Expr<'a, T> {
+        if l.dims() != r.dims() {
+            panic!("Dimensions mismatch for addition");
+        }
+        if subtract {
+            Expr::Sub(Box::new(l), Box::new(r))
+        } else {
+            Expr::Add(Box::new(l), Box::new(r))
+        }
+    }
+
+    fn product(l: Expr<'a, T>, r: Expr<'a, T>) -> Expr<'a, T> {
+        if l.dims().1 != r.dims().0 {
+            panic!("Dimensions mismatch for multiplication");
+        }
+        Expr::Mul(Box::new(l), Box::new(r))
+    }
+
+    // 同一个矩阵在表达式中出现多次时只快照一次; 预先算右操作数时溢出返回 None
+    fn compile(&self, snapshots: &mut HashMap<*const SparseMatrix<T>, Rows<T>>) -> Option<Plan<T>> {
+        Some(match self {
+            Expr::Matrix(m) => Plan::Rows(
+                snapshots.entry(*m as *const SparseMatrix<T>)
+                    .or_insert_with(|| Rc::new(m.row_lines()))
+                    .clone(),
+            ),
+            Expr::Add(l, r) => Plan::Add(Box::new(l.compile(snapshots)?), Box::new(r.compile(snapshots)?)),
+            Expr::Sub(l, r) => Plan::Sub(Box::new(l.compile(snapshots)?), Box::new(r.compile(snapshots)?)),
+            Expr::Mul(l, r) => {
+                let right = match r.compile(snapshots)? {
+                    Plan::Rows(rows) => rows,
+                    plan => {
+                        let mut rows = vec![Vec::new()];
+                        for i in 1..=r.dims().0 {
+                            rows.push(plan.row(i)?);
+                        }
+                        Rc::new(rows)
+                    }
+                };
+                Plan::Mul(Box::new(l.compile(snapshots)?), right)
+            }
+            Expr::Neg(e) => Plan::Neg(Box::new(e.compile(snapshots)?)),
+        })
+    }
+
+    // 元素运算溢出时 panic; 需要把溢出当作错误处理时用 checked_eval
+    pub fn eval(&self) -> SparseMatrix<T> {
+        self.checked_eval().unwrap_or_else(|| panic!("Arithmetic overflow while evaluating expression"))
+    }
+
+    // 任何一步加、减、乘或取负溢出都返回 None
+    pub fn checked_eval(&self) -> Option<SparseMatrix<T>> {
+        let (rows, cols) = self.dims();
+        let plan = self.compile(&mut HashMap::new())?;
+        let mut entries = Vec::new();
+        for i in 1..=rows {
+            entries.extend(plan.row(i)?.into_iter().map(|(c, v)| (i, c, v)));
+        }
+        let result = SparseMatrix::from_row_major(rows, cols, entries);
+        result.debug_validate("Expr::eval");
+        Some(result)
+    }
+}
+
+impl<T: Scalar> Plan<T> {
+    // 计算第 i 行, 返回按列号升序的 (col, value); 溢出时返回 None
+    fn row(&self, i: usize) -> Option<Vec<(usize, T)>> {
+        match self {
+            Plan::Rows(rows) => Some(rows[i].clone()),
+            Plan::Add(l, r) => merge(&l.row(i)?, &r.row(i)?, false),
+            Plan::Sub(l, r) => merge(&l.row(i)?, &r.row(i)?, true),
+            Plan::Mul(l, right) => {
+                let mut products: Vec<(usize, T)> = Vec::new();
+                for (k, a) in l.row(i)? {
+                    for &(j, b) in &right[k] {
+                        products.push((j, a.checked_mul(b)?));
+                    }
+                }
+                products.sort_by_key(|&(j, _)| j);
+                let mut out: Vec<(usize, T)> = Vec::with_capacity(products.len());
+                for (j, v) in products {
+                    match out.last_mut() {
+                        Some(last) if last.0 == j => last.1 = last.1.checked_add(v)?,
+                        _ => out.push((j, v)),
+                    }
+                }
+                out.retain(|&(_, v)| !v.is_zero());
+                Some(out)
+            }
+            Plan::Neg(e) => e.row(i)?.into_iter().map(|(c, v)| Some((c, v.checked_neg()?))).collect(),
+        }
+    }
+}
+
+// 与 add 相同的规则: 两边都有的位置相加后为零则舍去
+fn merge<T: Scalar>(a: &[(usize, T)], b: &[(usize, T)], subtract: bool) -> Option<Vec<(usize, T)>> {
+    let rhs = |v: T| if subtract { v.checked_neg() } else { Some(v) };
+    let mut out = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        let key_a = a.get(i).map_or(usize::MAX, |e| e.0);
+        let key_b = b.get(j).map_or(usize::MAX, |e| e.0);
+        if key_a < key_b {
+            out.push(a[i]);
+            i += 1;
+        } else if key_b < key_a {
+            out.push((key_b, rhs(b[j].1)?));
+            j += 1;
+        } else {
+            let val = if subtract { a[i].1.checked_sub(b[j].1)? } else { a[i].1.checked_add(b[j].1)? };
+            if !val.is_zero() {
+                out.push((key_a, val));
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+    Some(out)
+}
+
+impl<'a, T: Scalar> From<&'a SparseMatrix<T>> for Expr<'a, T> {
+    fn from(m: &'a SparseMatrix<T>) -> Self {
+        Expr::Matrix(m)
+    }
+}
+
+macro_rules! impl_expr_ops {
+    ($lhs:ty, $rhs:ty) => {
+        impl<'a, T: Scalar> Add<$rhs> for $lhs {
+            type Output = Expr<'a, T>;
+            fn add(self, rhs: $rhs) -> Expr<'a, T> {
+                Expr::sum(self.into(), rhs.into(), false)
+            }
+        }
+
+        impl<'a, T: Scalar> Sub<$rhs> for $lhs {
+            type Output = Expr<'a, T>;
+            fn sub(self, rhs: $rhs) -> Expr<'a, T> {
+                Expr::sum(self.into(), rhs.into(), true)
+            }
+        }
+
+        impl<'a, T: Scalar> Mul<$rhs> for $lhs {
+            type Output = Expr<'a, T>;
+            fn mul(self, rhs: $rhs) -> Expr<'a, T> {
+                Expr::product(self.into(), rhs.into())
+            }
+        }
+    };
+}
+
+impl_expr_ops!(&'a SparseMatrix<T>, &'a SparseMatrix<T>);
+impl_expr_ops!(&'a SparseMatrix<T>, Expr<'a, T>);
+impl_expr_ops!(Expr<'a, T>, &'a SparseMatrix<T>);
+impl_expr_ops!(Expr<'a, T>, Expr<'a, T>);
+
+impl<'a, T: Scalar> Neg for &'a SparseMatrix<T> {
+    type Output = Expr<'a, T>;
+    fn neg(self) -> Expr<'a, T> {
+        Expr::Neg(Box::new(self.into()))
+    }
+}
+
+impl<'a, T: Scalar> Neg for Expr<'a, T> {
+    type Output = Expr<'a, T>;
+    fn neg(self) -> Expr<'a, T> {
+        Expr::Neg(Box::new(self))
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashSet;
+
+use crate::{DuplicatePolicy, Scalar, SparseMatrix, SparseMatrixBuilder};
+
+// SplitMix64: 同一个种子在任何平台上都产生同一序列
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // [0, 1) 上的均匀分布
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // [0, n) 上的均匀整数, 拒绝采样避免取模偏差
+    fn below(&mut self, n: u64) -> u64 {
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    // 以 values 为对角线的方阵, 零值不存储
+    pub fn from_diagonal(values: &[T]) -> Self {
+        let n = values.len();
+        let entries = values.iter().enumerate()
+            .filter(|(_, v)| !v.is_zero())
+            .map(|(i, &v)| (i + 1, i + 1, v));
+        SparseMatrix::from_row_major(n, n, entries)
+    }
+
+    // 带状矩阵: 每个 (offset, value) 是一条常数对角线, offset 为 0 是主对角线, 正数在上方, 负数在下方
+    pub fn from_diagonals(n: usize, diagonals: &[(isize, T)]) -> Self {
+        let mut bands: Vec<(isize, T)> = diagonals.iter().copied().filter(|(_, v)| !v.is_zero()).collect();
+        bands.sort_by_key(|&(k, _)| k);
+        if let Some(w) = bands.windows(2).find(|w| w[0].0 == w[1].0) {
+            panic!("Diagonal offset {} given more than once", w[0].0);
+        }
+        let entries = (1..=n).flat_map(|r| {
+            bands.iter().filter_map(move |&(k, v)| {
+                let c = r as isize + k;
+                (c >= 1 && c <= n as isize).then_some((r, c as usize, v))
+            })
+        }).collect::<Vec<_>>();
+        SparseMatrix::from_row_major(n, n, entries)
+    }
+
+    pub fn tridiagonal(n: usize, sub: T, diag: T, sup: T) -> Self {
+        SparseMatrix::from_diagonals(n, &[(-1, sub), (0, diag), (1, sup)])
+    }
+
+    pub fn identity(n: usize) -> Self {
+        SparseMatrix::from_diagonal(&vec![T::one(); n])
+    }
+
+    // nx * ny 网格上的五点差分 Laplacian: 结点 (i, j) 编号为 (j - 1) * nx + i, 对角为 4, 相邻结点为 -1
+    pub fn laplacian_2d(nx: usize, ny: usize) -> Self {
+        let n = nx * ny;
+        let mut entries = Vec::with_capacity(5 * n);
+        for j in 1..=ny {
+            for i in 1..=nx {
+                let r = (j - 1) * nx + i;
+                if j > 1 {
+                    entries.push((r, r - nx, T::from(-1)));
+                }
+                if i > 1 {
+                    entries.push((r, r - 1, T::from(-1)));
+                }
+                entries.push((r, r, T::from(4)));
+                if i < nx {
+                    entries.push((r, r + 1, T::from(-1)));
+                }
+                if j < ny {
+                    entries.push((r, r + nx, T::from(-1)));
+                }
+            }
+        }
+        SparseMatrix::from_row_major(n, n, entries)
+    }
+
+    // 均匀随机稀疏矩阵: 恰有 round(density * rows * cols) 个非零元, 位置用 Floyd 算法无放回抽取,
+    // 数值为 1..=9 上的均匀整数
+    pub fn random_uniform(rows: usize, cols: usize, density: f64, seed: u64) -> Self {
+        if !(0.0..=1.0).contains(&density) {
+            panic!("Density {} must lie in [0, 1]", density);
+        }
+        let total = (rows as u64).checked_mul(cols as u64).expect("matrix too large to sample");
+        let k = ((density * total as f64).round() as u64).min(total);
+        let mut rng = SplitMix64::new(seed);
+
+        let mut chosen: HashSet<u64> = HashSet::with_capacity(k as usize);
+        for j in total - k..total {
+            let t = rng.below(j + 1);
+            if !chosen.insert(t) {
+                chosen.insert(j);
+            }
+        }
+        let mut positions: Vec<u64> = chosen.into_iter().collect();
+        positions.sort_unstable();
+
+        let entries = positions.into_iter().map(|p| {
+            let value = T::from(1 + rng.below(9) as i8);
+            ((p / cols as u64) as usize + 1, (p % cols as u64) as usize + 1, value)
+        });
+        SparseMatrix::from_row_major(rows, cols, entries)
+    }
+
+    // R-MAT 幂律图: 2^scale 个结点, 每条边在四个象限中按概率 (a, b, c, 1 - a - b - c) 递归下降;
+    // 重复的边只保留一次, 邻接矩阵元素为 1
+    pub fn rmat(scale: u32, edges: usize, a: f64, b: f64, c: f64, seed: u64) -> Self {
+        // 容许 a + b + c 因舍入略大于 1
+        let d = 1.0 - a - b - c;
+        if [a, b, c].iter().any(|&p| !(0.0..=1.0).contains(&p)) || d < -1e-12 {
+            panic!("R-MAT probabilities must be non-negative and sum to at most 1");
+        }
+        let n = 1usize.checked_shl(scale).expect("R-MAT scale too large");
+        let mut rng = SplitMix64::new(seed);
+        let mut builder = SparseMatrixBuilder::new(n, n).duplicate_policy(DuplicatePolicy::KeepFirst);
+        builder.reserve(edges);
+        for _ in 0..edges {
+            let (mut row, mut col) = (0, 0);
+            for bit in (0..scale).rev() {
+                let p = rng.next_f64();
+                let (down, right) = if p < a {
+                    (false, false)
+                } else if p < a + b {
+                    (false, true)
+                } else if p < a + b + c {
+                    (true, false)
+                } else {
+                    (true, true)
+                };
+                row |= usize::from(down) << bit;
+                col |= usize::from(right) << bit;
+            }
+            builder.push(row + 1, col + 1, T::one()).expect("R-MAT edges lie inside the matrix");
+        }
+        builder.build().expect("duplicate edges are merged")
+    }
+}
@@ -0,0 +1,278 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::binary::{BinaryError, BinaryScalar};
+use crate::{Scalar, SparseMatrix};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    // %%MatrixMarket matrix coordinate ...
+    MatrixMarket,
+    // 首行 "rows cols", 其后每行 "row col value", # 开头为注释
+    Triplet,
+    // 见 binary.rs
+    Binary,
+}
+
+impl Format {
+    // 按扩展名推断: .mtx / .mm 为 Matrix Market, .smx 为二进制, 其余按三元组文本处理
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Format {
+        match path.as_ref().extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("mtx") || ext.eq_ignore_ascii_case("mm") => Format::MatrixMarket,
+            Some(ext) if ext.eq_ignore_ascii_case("smx") => Format::Binary,
+            _ => Format::Triplet,
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "mm" | "mtx" | "matrix-market" => Ok(Format::MatrixMarket),
+            "triplet" | "triplets" | "txt" => Ok(Format::Triplet),
+            "binary" | "bin" | "smx" => Ok(Format::Binary),
+            _ => Err(format!("unknown matrix format '{}' (expected 'mm', 'triplet' or 'binary')", s)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+    Binary(BinaryError),
+}
+
+impl ReadError {
+    fn parse(line: usize, message: impl Into<String>) -> Self {
+        ReadError::Parse { line, message: message.into() }
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "{}", e),
+            ReadError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            ReadError::Binary(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(e) => Some(e),
+            ReadError::Parse { .. } => None,
+            ReadError::Binary(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+fn parse_field<T: FromStr>(token: Option<&str>, line: usize, what: &str) -> Result<T, ReadError> {
+    let token = token.ok_or_else(|| ReadError::parse(line, format!("missing {}", what)))?;
+    token.parse().map_err(|_| ReadError::parse(line, format!("invalid {} '{}'", what, token)))
+}
+
+fn parse_index(token: Option<&str>, n: usize, line: usize, what: &str) -> Result<usize, ReadError> {
+    let i: usize = parse_field(token, line, what)?;
+    if i == 0 || i > n {
+        return Err(ReadError::parse(line, format!("{} {} out of bounds (valid indices are 1..={})", what, i, n)));
+    }
+    Ok(i)
+}
+
+// 一行的字段读完后不允许再有多余的内容
+fn expect_end<'a>(mut tokens: impl Iterator<Item = &'a str>, line: usize) -> Result<(), ReadError> {
+    match tokens.next() {
+        Some(extra) => Err(ReadError::parse(line, format!("unexpected trailing '{}'", extra))),
+        None => Ok(()),
+    }
+}
+
+// 越界已在解析时检查, 这里只拒绝重复坐标, 其余交给 from_triplets
+fn finish<T: Scalar>(rows: usize, cols: usize, mut entries: Vec<(usize, usize, T, usize)>) -> Result<SparseMatrix<T>, ReadError> {
+    entries.sort_by_key(|&(r, c, _, line)| (r, c, line));
+    if let Some(w) = entries.windows(2).find(|w| (w[0].0, w[0].1) == (w[1].0, w[1].1)) {
+        return Err(ReadError::parse(
+            w[1].3,
+            format!("duplicate entry ({}, {}), first given on line {}", w[1].0, w[1].1, w[0].3),
+        ));
+    }
+    let triplets = entries.into_iter().map(|(r, c, v, _)| (r, c, v)).collect();
+    Ok(SparseMatrix::from_triplets(rows, cols, triplets))
+}
+
+// 文本格式: 值按元素类型解析, 整数矩阵写出 integer 字段, 浮点矩阵写出 real 字段
+impl<T: Scalar> SparseMatrix<T> {
+    pub fn read_matrix_market<R: BufRead>(reader: R) -> Result<SparseMatrix<T>, ReadError> {
+        let mut lines = reader.lines().enumerate().map(|(i, l)| (i + 1, l));
+
+        let header = match lines.next() {
+            Some((_, l)) => l?,
+            None => return Err(ReadError::parse(1, "empty file")),
+        };
+        let banner: Vec<String> = header.split_whitespace().map(|t| t.to_ascii_lowercase()).collect();
+        if banner.len() != 5 || banner[0] != "%%matrixmarket" || banner[1] != "matrix" {
+            return Err(ReadError::parse(1, "expected '%%MatrixMarket matrix coordinate <field> <symmetry>'"));
+        }
+        if banner[2] != "coordinate" {
+            return Err(ReadError::parse(1, format!("unsupported storage '{}', only 'coordinate' is supported", banner[2])));
+        }
+        let field = banner[3].as_str();
+        if !matches!(field, "integer" | "real" | "pattern") {
+            return Err(ReadError::parse(1, format!("unsupported field '{}'", field)));
+        }
+        let symmetry = banner[4].as_str();
+        if !matches!(symmetry, "general" | "symmetric" | "skew-symmetric") {
+            return Err(ReadError::parse(1, format!("unsupported symmetry '{}'", symmetry)));
+        }
+
+        let mut size: Option<(usize, usize, usize)> = None;
+        let mut entries = Vec::new();
+        let mut seen = 0;
+        for (line_no, line) in lines {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('%') {
+                continue;
+            }
+            let mut tokens = line.split_whitespace();
+            let Some((rows, cols, nnz)) = size else {
+                size = Some((
+                    parse_field(tokens.next(), line_no, "row count")?,
+                    parse_field(tokens.next(), line_no, "column count")?,
+                    parse_field(tokens.next(), line_no, "entry count")?,
+                ));
+                expect_end(tokens, line_no)?;
+                continue;
+            };
+            if seen == nnz {
+                return Err(ReadError::parse(line_no, format!("more than the declared {} entries", nnz)));
+            }
+            seen += 1;
+
+            let r = parse_index(tokens.next(), rows, line_no, "row")?;
+            let c = parse_index(tokens.next(), cols, line_no, "column")?;
+            let v = match field {
+                "pattern" => T::one(),
+                "integer" => parse_field(tokens.next(), line_no, "value")?,
+                _ => {
+                    let x: f64 = parse_field(tokens.next(), line_no, "value")?;
+                    T::from_f64_exact(x).ok_or_else(|| {
+                        ReadError::parse(line_no, format!("value {} is not representable as {}", x, std::any::type_name::<T>()))
+                    })?
+                }
+            };
+            expect_end(tokens, line_no)?;
+            entries.push((r, c, v, line_no));
+            if r != c {
+                match symmetry {
+                    "symmetric" => entries.push((c, r, v, line_no)),
+                    "skew-symmetric" => entries.push((c, r, -v, line_no)),
+                    _ => {}
+                }
+            }
+        }
+
+        let Some((rows, cols, nnz)) = size else {
+            return Err(ReadError::parse(1, "missing size line"));
+        };
+        if seen != nnz {
+            return Err(ReadError::parse(1, format!("declared {} entries but found {}", nnz, seen)));
+        }
+        finish(rows, cols, entries)
+    }
+
+    pub fn write_matrix_market<W: Write>(&self, mut w: W) -> io::Result<()> {
+        let (rows, cols) = self.dims();
+        let entries = self.triplets();
+        let field = if T::INTEGRAL { "integer" } else { "real" };
+        writeln!(w, "%%MatrixMarket matrix coordinate {} general", field)?;
+        writeln!(w, "{} {} {}", rows, cols, entries.len())?;
+        for (r, c, v) in entries {
+            writeln!(w, "{} {} {}", r, c, v)?;
+        }
+        w.flush()
+    }
+
+    pub fn read_triplets<R: BufRead>(reader: R) -> Result<SparseMatrix<T>, ReadError> {
+        let mut size: Option<(usize, usize)> = None;
+        let mut entries = Vec::new();
+        for (i, line) in reader.lines().enumerate() {
+            let (line_no, line) = (i + 1, line?);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut tokens = line.split_whitespace();
+            let Some((rows, cols)) = size else {
+                size = Some((
+                    parse_field(tokens.next(), line_no, "row count")?,
+                    parse_field(tokens.next(), line_no, "column count")?,
+                ));
+                expect_end(tokens, line_no)?;
+                continue;
+            };
+            let r = parse_index(tokens.next(), rows, line_no, "row")?;
+            let c = parse_index(tokens.next(), cols, line_no, "column")?;
+            let v = parse_field(tokens.next(), line_no, "value")?;
+            expect_end(tokens, line_no)?;
+            entries.push((r, c, v, line_no));
+        }
+        let Some((rows, cols)) = size else {
+            return Err(ReadError::parse(1, "missing 'rows cols' header"));
+        };
+        finish(rows, cols, entries)
+    }
+
+    pub fn write_triplets<W: Write>(&self, mut w: W) -> io::Result<()> {
+        let (rows, cols) = self.dims();
+        writeln!(w, "{} {}", rows, cols)?;
+        for (r, c, v) in self.triplets() {
+            writeln!(w, "{} {} {}", r, c, v)?;
+        }
+        w.flush()
+    }
+}
+
+// 统一入口也覆盖二进制格式, 因此要求元素类型能写成二进制
+impl<T: BinaryScalar> SparseMatrix<T> {
+    pub fn read<R: BufRead>(reader: R, format: Format) -> Result<SparseMatrix<T>, ReadError> {
+        match format {
+            Format::MatrixMarket => SparseMatrix::read_matrix_market(reader),
+            Format::Triplet => SparseMatrix::read_triplets(reader),
+            Format::Binary => SparseMatrix::read_binary(reader).map_err(ReadError::Binary),
+        }
+    }
+
+    pub fn write<W: Write>(&self, w: W, format: Format) -> io::Result<()> {
+        match format {
+            Format::MatrixMarket => self.write_matrix_market(w),
+            Format::Triplet => self.write_triplets(w),
+            Format::Binary => self.write_binary(w),
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P, format: Format) -> Result<SparseMatrix<T>, ReadError> {
+        let file = File::open(path)?;
+        SparseMatrix::read(BufReader::new(file), format)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P, format: Format) -> io::Result<()> {
+        let file = File::create(path)?;
+        self.write(BufWriter::new(file), format)
+    }
+}
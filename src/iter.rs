@@ -0,0 +1,147 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::vec;
+
+use crate::{MatrixNode, Scalar, SparseMatrix};
+
+// 按行主序产出 (row, col, value); 对称存储的矩阵也产出镜像的上三角元素
+pub struct Triplets<T = i32> {
+    inner: vec::IntoIter<(usize, usize, T)>,
+}
+
+impl<T> Iterator for Triplets<T> {
+    type Item = (usize, usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Triplets<T> {}
+
+impl<T: Scalar> SparseMatrix<T> {
+    pub fn iter(&self) -> Triplets<T> {
+        Triplets { inner: self.triplets().into_iter() }
+    }
+
+    // 给定维数的 collect: 语义与 extend 相同 (同一坐标以最后一次为准, 0 不存储), 越界的坐标 panic
+    pub fn from_entries<I: IntoIterator<Item = (usize, usize, T)>>(rows: usize, cols: usize, iter: I) -> Self {
+        let mut matrix = SparseMatrix::new(rows, cols);
+        matrix.extend(iter);
+        matrix
+    }
+}
+
+impl<T: Scalar> IntoIterator for SparseMatrix<T> {
+    type Item = (usize, usize, T);
+    type IntoIter = Triplets<T>;
+
+    fn into_iter(self) -> Triplets<T> {
+        self.iter()
+    }
+}
+
+impl<T: Scalar> IntoIterator for &SparseMatrix<T> {
+    type Item = (usize, usize, T);
+    type IntoIter = Triplets<T>;
+
+    fn into_iter(self) -> Triplets<T> {
+        self.iter()
+    }
+}
+
+// 与 set 的语义一致: 已有坐标改值, 同一坐标以最后一次为准, 写入 0 会删除结点
+impl<T: Scalar> Extend<(usize, usize, T)> for SparseMatrix<T> {
+    fn extend<I: IntoIterator<Item = (usize, usize, T)>>(&mut self, iter: I) {
+        let mut entries: Vec<(usize, usize, T)> = Vec::new();
+        for (r, c, v) in iter {
+            self.check_position(r, c);
+            let (r, c) = self.storage_position(r, c);
+            entries.push((r, c, v));
+        }
+        // 稳定排序后去重, 保留每个坐标最后写入的值
+        entries.sort_by_key(|&(r, c, _)| (r, c));
+        let mut merged: Vec<(usize, usize, T)> = Vec::with_capacity(entries.len());
+        for (r, c, v) in entries {
+            match merged.last_mut() {
+                Some(last) if (last.0, last.1) == (r, c) => last.2 = v,
+                _ => merged.push((r, c, v)),
+            }
+        }
+
+        // right 链: 与已有结点归并, 新坐标就地插入, 改写为 0 的结点就地摘下;
+        // 记下这些结点, 之后在 down 链上做同样的修改 (true 表示摘下)
+        let mut touched = Vec::new();
+        let mut prev = self.head.clone();
+        for (r, c, v) in merged {
+            let next = loop {
+                let next = prev.borrow().right.clone().unwrap();
+                if Rc::ptr_eq(&next, &self.head) || (next.borrow().row, next.borrow().col) >= (r, c) {
+                    break next;
+                }
+                prev = next;
+            };
+            let exists = !Rc::ptr_eq(&next, &self.head) && (next.borrow().row, next.borrow().col) == (r, c);
+            if exists && v.is_zero() {
+                let after = next.borrow().right.clone();
+                prev.borrow_mut().right = after;
+                touched.push((next, true));
+                continue;
+            }
+            if exists {
+                next.borrow_mut().value = v;
+                continue;
+            }
+            if v.is_zero() {
+                continue;
+            }
+            let node = Rc::new(RefCell::new(MatrixNode::new(r, c, v)));
+            node.borrow_mut().right = Some(next);
+            prev.borrow_mut().right = Some(node.clone());
+            prev = node.clone();
+            touched.push((node, false));
+        }
+
+        // down 链: 按 (col, row) 排序后一次归并, 插入新结点、摘下置零的结点
+        touched.sort_by_key(|(node, _)| {
+            let n = node.borrow();
+            (n.col, n.row)
+        });
+        let mut prev = self.head.clone();
+        for (node, remove) in touched {
+            let key = (node.borrow().col, node.borrow().row);
+            loop {
+                let next = prev.borrow().down.clone().unwrap();
+                if Rc::ptr_eq(&next, &self.head) || (next.borrow().col, next.borrow().row) >= key {
+                    break;
+                }
+                prev = next;
+            }
+            if remove {
+                let after = node.borrow_mut().down.take();
+                prev.borrow_mut().down = after;
+                continue;
+            }
+            let after = prev.borrow_mut().down.take();
+            node.borrow_mut().down = after;
+            prev.borrow_mut().down = Some(node.clone());
+            prev = node;
+        }
+        self.debug_validate("extend");
+    }
+}
+
+// 维数由元素推断: 行数、列数分别取出现过的最大行号和列号, 没有元素时为 0x0.
+// 因此末尾的全零行/列不会出现在结果中; 需要固定维数时用 SparseMatrix::from_entries
+impl<T: Scalar> FromIterator<(usize, usize, T)> for SparseMatrix<T> {
+    fn from_iter<I: IntoIterator<Item = (usize, usize, T)>>(iter: I) -> Self {
+        let entries: Vec<(usize, usize, T)> = iter.into_iter().collect();
+        let rows = entries.iter().map(|e| e.0).max().unwrap_or(0);
+        let cols = entries.iter().map(|e| e.1).max().unwrap_or(0);
+        SparseMatrix::from_entries(rows, cols, entries)
+    }
+}
@@ -0,0 +1,582 @@
+use std::rc::Rc;
+use std::cell::{RefCell, Ref}; 
+use std::io::Write;
+
+mod binary;
+mod builder;
+mod calc;
+mod cholesky;
+mod compare;
+mod dense;
+mod eigen;
+mod expr;
+mod generate;
+mod io;
+mod iter;
+mod ordering;
+mod pagerank;
+mod parallel;
+mod reductions;
+mod scalar;
+#[cfg(feature = "serde")]
+mod serde_support;
+mod spy;
+mod stats;
+mod structural;
+mod submatrix;
+mod symmetric;
+mod validate;
+mod vector;
+
+pub use binary::{BinaryError, BinaryScalar};
+pub use builder::{DuplicatePolicy, SparseMatrixBuilder};
+pub use calc::{CalcError, Session};
+pub use cholesky::{Cholesky, CholeskyError, SymbolicCholesky};
+pub use compare::MatrixMismatch;
+pub use dense::DenseMatrix;
+pub use eigen::{EigenError, EigenOptions, EigenResult, LanczosResult, Which};
+pub use expr::Expr;
+pub use io::{Format, ReadError};
+pub use iter::Triplets;
+pub use ordering::Ordering;
+pub use pagerank::{PageRankOptions, PageRankResult};
+pub use scalar::Scalar;
+pub use spy::SpyStyle;
+pub use stats::MatrixStats;
+pub use validate::{Chain, InvariantViolation};
+pub use vector::SparseVector;
+
+type Link<T = i32> = Rc<RefCell<MatrixNode<T>>>;
+
+struct MatrixNode<T = i32> {
+    row: usize,
+    col: usize,
+    value: T,
+    right: Option<Link<T>>,
+    down: Option<Link<T>>,
+}
+
+impl<T> MatrixNode<T> {
+    fn new(row: usize, col: usize, value: T) -> Self {
+        MatrixNode {
+            row,
+            col,
+            value,
+            right: None,
+            down: None,
+        }
+    }
+}
+
+
+// 值类型默认为 i32, 数值计算 (如 Cholesky 分解) 使用 SparseMatrix<f64>
+pub struct SparseMatrix<T = i32> {
+    head: Link<T>, 
+    // 对称存储: 链表中只保存下三角 (row >= col) 部分
+    symmetric: bool,
+}
+
+// 构造前对三元组输入的检查结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TripletError {
+    OutOfBounds { row: usize, col: usize, rows: usize, cols: usize },
+    Duplicate { row: usize, col: usize },
+}
+
+impl std::fmt::Display for TripletError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TripletError::OutOfBounds { row, col, rows, cols } => {
+                write!(f, "entry ({}, {}) lies outside the {}x{} matrix", row, col, rows, cols)
+            }
+            TripletError::Duplicate { row, col } => write!(f, "entry ({}, {}) is given more than once", row, col),
+        }
+    }
+}
+
+impl std::error::Error for TripletError {}
+
+impl<T: Scalar> SparseMatrix<T> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        let head_node = Rc::new(RefCell::new(MatrixNode::new(rows, cols, T::zero())));
+        
+        head_node.borrow_mut().right = Some(head_node.clone());
+        head_node.borrow_mut().down = Some(head_node.clone());
+
+        SparseMatrix { head: head_node, symmetric: false }
+    }
+
+    pub fn dims(&self) -> (usize, usize) {
+        let h = self.head.borrow();
+        (h.row, h.col)
+    }
+
+    // 沿 right 链按行主序收集实际存储的结点
+    fn stored_triplets(&self) -> Vec<(usize, usize, T)> {
+        let mut out = Vec::new();
+        let mut p = self.head.borrow().right.clone().unwrap();
+        while !Rc::ptr_eq(&p, &self.head) {
+            let next = {
+                let node = p.borrow();
+                out.push((node.row, node.col, node.value));
+                node.right.clone().unwrap()
+            };
+            p = next;
+        }
+        out
+    }
+
+    // 沿 down 链按列主序收集实际存储的结点
+    fn stored_col_major_triplets(&self) -> Vec<(usize, usize, T)> {
+        let mut out = Vec::new();
+        let mut p = self.head.borrow().down.clone().unwrap();
+        while !Rc::ptr_eq(&p, &self.head) {
+            let next = {
+                let node = p.borrow();
+                out.push((node.row, node.col, node.value));
+                node.down.clone().unwrap()
+            };
+            p = next;
+        }
+        out
+    }
+
+    // 按行主序收集所有非零元; 对称存储时把下三角与镜像出的上三角归并
+    fn triplets(&self) -> Vec<(usize, usize, T)> {
+        let lower = self.stored_triplets();
+        if !self.symmetric {
+            return lower;
+        }
+        // 下三角的列主序 (c, r) 恰好是镜像上三角的行主序
+        let upper: Vec<(usize, usize, T)> = self.stored_col_major_triplets()
+            .into_iter()
+            .filter(|&(r, c, _)| r != c)
+            .map(|(r, c, v)| (c, r, v))
+            .collect();
+        let mut out = Vec::with_capacity(lower.len() + upper.len());
+        let (mut i, mut j) = (0, 0);
+        while i < lower.len() && j < upper.len() {
+            if (lower[i].0, lower[i].1) < (upper[j].0, upper[j].1) {
+                out.push(lower[i]);
+                i += 1;
+            } else {
+                out.push(upper[j]);
+                j += 1;
+            }
+        }
+        out.extend_from_slice(&lower[i..]);
+        out.extend_from_slice(&upper[j..]);
+        out
+    }
+
+    // 按列主序收集所有非零元
+    fn col_major_triplets(&self) -> Vec<(usize, usize, T)> {
+        if !self.symmetric {
+            return self.stored_col_major_triplets();
+        }
+        // 对称矩阵的列主序就是行主序交换行列号
+        self.triplets().into_iter().map(|(r, c, v)| (c, r, v)).collect()
+    }
+
+    // 按行分组: 下标 i 对应第 i 行 (0 号位置不用)
+    fn row_lines(&self) -> Vec<Vec<(usize, T)>> {
+        let (rows, _) = self.dims();
+        let mut lines = vec![Vec::new(); rows + 1];
+        for (r, c, v) in self.triplets() {
+            lines[r].push((c, v));
+        }
+        lines
+    }
+
+    fn col_lines(&self) -> Vec<Vec<(usize, T)>> {
+        let (_, cols) = self.dims();
+        let mut lines = vec![Vec::new(); cols + 1];
+        for (r, c, v) in self.col_major_triplets() {
+            lines[c].push((r, v));
+        }
+        lines
+    }
+
+    // 由已按 (row, col) 排好序的三元组一次性串起 right/down 两条循环链表
+    fn from_row_major<I>(rows: usize, cols: usize, entries: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, T)>,
+    {
+        let nodes = entries.into_iter().map(|(r, c, v)| Rc::new(RefCell::new(MatrixNode::new(r, c, v))));
+        SparseMatrix::from_row_major_nodes(rows, cols, nodes)
+    }
+
+    // 同上, 但直接串接已分配好的结点 (原有的 right/down 指针会被覆盖)
+    // 列数远多于元素时按列排序结点, 不按列数分配桶, 避免极宽的稀疏矩阵占用 O(cols) 内存
+    fn from_row_major_nodes<I>(rows: usize, cols: usize, entries: I) -> Self
+    where
+        I: IntoIterator<Item = Link<T>>,
+    {
+        let matrix = SparseMatrix::new(rows, cols);
+        let head = matrix.head.clone();
+        let mut last_right = head.clone();
+        let mut nodes: Vec<Link<T>> = Vec::new();
+        for node in entries {
+            last_right.borrow_mut().right = Some(node.clone());
+            last_right = node.clone();
+            nodes.push(node);
+        }
+        last_right.borrow_mut().right = Some(head.clone());
+
+        let mut last_down = head.clone();
+        if cols <= 2 * nodes.len() + 16 {
+            let mut col_heads: Vec<Option<Link<T>>> = vec![None; cols + 1];
+            let mut col_tails: Vec<Option<Link<T>>> = vec![None; cols + 1];
+            for node in nodes {
+                let c = node.borrow().col;
+                match &col_tails[c] {
+                    Some(tail) => tail.borrow_mut().down = Some(node.clone()),
+                    None => col_heads[c] = Some(node.clone()),
+                }
+                col_tails[c] = Some(node);
+            }
+            for (first, tail) in col_heads.into_iter().zip(col_tails) {
+                if let (Some(first), Some(tail)) = (first, tail) {
+                    last_down.borrow_mut().down = Some(first);
+                    last_down = tail;
+                }
+            }
+        } else {
+            // 稳定排序, 同一列内保持行号升序
+            nodes.sort_by_key(|node| node.borrow().col);
+            for node in nodes {
+                last_down.borrow_mut().down = Some(node.clone());
+                last_down = node;
+            }
+        }
+        last_down.borrow_mut().down = Some(head);
+        matrix
+    }
+
+    // 先检查越界和重复坐标, 再交给 from_triplets
+    pub fn try_from_triplets(rows: usize, cols: usize, mut triplets: Vec<(usize, usize, T)>) -> Result<Self, TripletError> {
+        if let Some(&(row, col, _)) = triplets.iter().find(|&&(r, c, _)| r == 0 || r > rows || c == 0 || c > cols) {
+            return Err(TripletError::OutOfBounds { row, col, rows, cols });
+        }
+        triplets.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+        if let Some(w) = triplets.windows(2).find(|w| (w[0].0, w[0].1) == (w[1].0, w[1].1)) {
+            return Err(TripletError::Duplicate { row: w[1].0, col: w[1].1 });
+        }
+        Ok(SparseMatrix::from_triplets(rows, cols, triplets))
+    }
+
+    // 重复坐标的值相加; 与 set 一样不存储 0 (包括相加后抵消为 0 的坐标)
+    // 越界坐标会 panic; 需要把越界或重复坐标当作错误返回时用 try_from_triplets
+    pub fn from_triplets(rows: usize, cols: usize, mut triplets: Vec<(usize, usize, T)>) -> Self {
+        if let Some(&(r, c, _)) = triplets.iter().find(|&&(r, c, _)| r == 0 || r > rows || c == 0 || c > cols) {
+            panic!("Triplet ({}, {}) is out of bounds for a {}x{} matrix", r, c, rows, cols);
+        }
+        let matrix = SparseMatrix::new(rows, cols);
+        let head = matrix.head.clone();
+        triplets.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+        triplets.dedup_by(|next, kept| {
+            let same = (next.0, next.1) == (kept.0, kept.1);
+            if same {
+                kept.2 += next.2;
+            }
+            same
+        });
+        triplets.retain(|&(_, _, v)| !v.is_zero());
+        if triplets.is_empty() { return matrix; }
+        let nodes: Vec<Link<T>> = triplets.iter()
+            .map(|&(r, c, v)| Rc::new(RefCell::new(MatrixNode::new(r, c, v))))
+            .collect();
+        let mut last = head.clone();
+        for node in &nodes {
+            last.borrow_mut().right = Some(node.clone());
+            last = node.clone();
+        }
+        last.borrow_mut().right = Some(head.clone()); 
+        let mut node_indices: Vec<usize> = (0..nodes.len()).collect();
+        node_indices.sort_by(|&i, &j| {
+            let n_i = nodes[i].borrow();
+            let n_j = nodes[j].borrow();
+            n_i.col.cmp(&n_j.col).then(n_i.row.cmp(&n_j.row))
+        });
+        let mut last = head.clone();
+        for idx in node_indices {
+            let node = &nodes[idx];
+            last.borrow_mut().down = Some(node.clone());
+            last = node.clone();
+        }
+        last.borrow_mut().down = Some(head.clone());
+        matrix.debug_validate("from_triplets");
+        matrix
+    }
+    //加法
+    pub fn add(&self, other: &SparseMatrix<T>) -> SparseMatrix<T> {
+        // 两个对称存储的矩阵直接相加下三角; 存储方式不同时先展开
+        if self.symmetric != other.symmetric {
+            return self.to_general_storage().add(&other.to_general_storage());
+        }
+        let (rows, cols) = self.dims();
+        let mut result = SparseMatrix::new(rows, cols);
+        result.symmetric = self.symmetric;
+        let res_head = result.head.clone();
+
+        let mut p_a = self.head.borrow().right.clone().unwrap();
+        let mut p_b = other.head.borrow().right.clone().unwrap();
+
+        let mut last_right = res_head.clone(); 
+        
+        let mut col_heads: Vec<Option<Link<T>>> = vec![None; cols + 1];
+        let mut col_tails: Vec<Option<Link<T>>> = vec![None; cols + 1];
+
+        loop {
+            let a_is_head = Rc::ptr_eq(&p_a, &self.head);
+            let b_is_head = Rc::ptr_eq(&p_b, &other.head);
+
+            if a_is_head && b_is_head { break; }
+
+            let next_a;
+            let next_b;
+
+            let val;
+            let curr_row;
+            let curr_col;
+            let mut has_node = false;
+
+            let key_a = if a_is_head { (usize::MAX, usize::MAX) } else { (p_a.borrow().row, p_a.borrow().col) };
+            let key_b = if b_is_head { (usize::MAX, usize::MAX) } else { (p_b.borrow().row, p_b.borrow().col) };
+
+            if key_a < key_b {
+                { 
+                    let node_a: Ref<MatrixNode<T>> = p_a.borrow(); 
+                    curr_row = key_a.0;
+                    curr_col = key_a.1;
+                    val = node_a.value;
+                    next_a = node_a.right.clone().unwrap();
+                }
+                p_a = next_a; 
+                has_node = true;
+            } else if key_b < key_a {
+
+                { 
+                    let node_b: Ref<MatrixNode<T>> = p_b.borrow(); 
+                    curr_row = key_b.0;
+                    curr_col = key_b.1;
+                    val = node_b.value;
+                    next_b = node_b.right.clone().unwrap();
+                } 
+                p_b = next_b; 
+                has_node = true;
+            } else {
+                { 
+                    let node_a = p_a.borrow(); 
+                    let node_b = p_b.borrow(); 
+                    curr_row = key_a.0;
+                    curr_col = key_a.1;
+                    val = node_a.value + node_b.value;
+                    next_a = node_a.right.clone().unwrap();
+                    next_b = node_b.right.clone().unwrap();
+                } 
+                
+                p_a = next_a;
+                p_b = next_b;
+                
+                if !val.is_zero() { has_node = true; }
+            }
+
+            if has_node {
+                let new_node = Rc::new(RefCell::new(MatrixNode::new(curr_row, curr_col, val)));
+                last_right.borrow_mut().right = Some(new_node.clone());
+                last_right = new_node.clone();
+                if col_heads[curr_col].is_none() {
+                    col_heads[curr_col] = Some(new_node.clone());
+                } else {
+                    let tail = col_tails[curr_col].as_ref().unwrap();
+                    tail.borrow_mut().down = Some(new_node.clone());
+                }
+                col_tails[curr_col] = Some(new_node.clone());
+            }
+        }
+        last_right.borrow_mut().right = Some(res_head.clone());
+
+        let mut last_down = res_head.clone();
+        
+        for j in 1..=cols {
+            if let Some(col_first) = &col_heads[j] {
+                last_down.borrow_mut().down = Some(col_first.clone());
+                last_down = col_tails[j].as_ref().unwrap().clone();
+            }
+        }
+        last_down.borrow_mut().down = Some(res_head.clone());
+
+        result.debug_validate("add");
+        result
+    }
+    //乘法
+    pub fn multiply(&self, other: &SparseMatrix<T>) -> SparseMatrix<T> {
+        let (r_a, c_a) = self.dims();
+        let (r_b, c_b) = other.dims();
+        
+        if c_a != r_b {
+            panic!("Dimensions mismatch for multiplication");
+        }
+        if self.symmetric || other.symmetric {
+            return self.to_general_storage().multiply(&other.to_general_storage());
+        }
+
+        let mut triplets: Vec<(usize, usize, T)> = Vec::new();
+
+        let mut p_a = self.head.borrow().right.clone().unwrap();
+        
+        let mut current_row_a_idx = 0;
+        let mut row_a_nodes: Vec<Link<T>> = Vec::new();
+
+        loop {
+            let a_is_head = Rc::ptr_eq(&p_a, &self.head);
+            
+            let next_a;
+
+            let row_changed = !a_is_head && p_a.borrow().row != current_row_a_idx;
+            
+            if row_changed || a_is_head {
+                if !row_a_nodes.is_empty() {
+                    //当前行 A[i] 与 整个矩阵 B 的乘法
+                    let mut p_b = other.head.borrow().down.clone().unwrap();
+                    let mut current_col_b_idx = 0;
+                    let mut col_b_sum = T::zero();
+                    
+                    loop {
+                        let b_is_head = Rc::ptr_eq(&p_b, &other.head);
+                        let next_b;
+
+                        let b_row_changed = !b_is_head && p_b.borrow().col != current_col_b_idx;
+
+                        if b_row_changed || b_is_head {
+                            if !col_b_sum.is_zero() {
+                                triplets.push((current_row_a_idx, current_col_b_idx, col_b_sum));
+                                col_b_sum = T::zero();
+                            }
+                            if b_is_head { break; }
+                            current_col_b_idx = p_b.borrow().col;
+                        }
+                        {
+                            let node_b = p_b.borrow(); 
+                            let b_row = node_b.row;
+                            let b_val = node_b.value;
+                            next_b = node_b.down.clone().unwrap();
+
+                            for node_a in &row_a_nodes {
+                                let n_a = node_a.borrow();
+                                if n_a.col == b_row {
+                                    col_b_sum += n_a.value * b_val;
+                                }
+                            }
+                        } 
+
+                        p_b = next_b;
+                    }
+                }
+                
+                row_a_nodes.clear();
+                if a_is_head { break; }
+                {
+                    current_row_a_idx = p_a.borrow().row;
+                }
+                
+            }
+            {
+                let node_a = p_a.borrow(); 
+                row_a_nodes.push(p_a.clone());
+                next_a = node_a.right.clone().unwrap();
+            }
+            p_a = next_a; 
+        }
+
+        let product = SparseMatrix::from_triplets(r_a, c_b, triplets);
+        product.debug_validate("multiply");
+        product
+    }
+
+    //转置: 列主序的 (col, row) 恰好是转置矩阵的行主序
+    pub fn transpose(&self) -> SparseMatrix<T> {
+        if self.symmetric {
+            return self.clone();
+        }
+        let (rows, cols) = self.dims();
+        let entries = self.col_major_triplets().into_iter().map(|(r, c, v)| (c, r, v));
+        SparseMatrix::from_row_major(cols, rows, entries)
+    }
+
+    //矩阵向量乘法
+    pub fn mul_vec(&self, x: &[T]) -> Vec<T> {
+        let (rows, cols) = self.dims();
+        if x.len() != cols {
+            panic!("Dimensions mismatch for matrix-vector product");
+        }
+        let mut y = vec![T::zero(); rows];
+        for (r, c, v) in self.stored_triplets() {
+            y[r - 1] += v * x[c - 1];
+            if self.symmetric && r != c {
+                y[c - 1] += v * x[r - 1];
+            }
+        }
+        y
+    }
+
+    // 逐元素变换, 如 a.map(f64::from) 得到浮点矩阵; 结果为零的元素不存储
+    pub fn map<U: Scalar, F: Fn(T) -> U>(&self, f: F) -> SparseMatrix<U> {
+        let (rows, cols) = self.dims();
+        let entries = self.stored_triplets().into_iter()
+            .map(|(r, c, v)| (r, c, f(v)))
+            .filter(|(_, _, v)| !v.is_zero());
+        let mut mapped = SparseMatrix::from_row_major(rows, cols, entries);
+        mapped.symmetric = self.symmetric;
+        mapped
+    }
+
+    pub fn print(&self) {
+        self.write_dense(std::io::stdout().lock()).expect("failed to write matrix to stdout");
+    }
+
+    // print 的输出格式: 逐行写出所有元素 (含 0)
+    pub fn write_dense<W: Write>(&self, w: W) -> std::io::Result<()> {
+        if self.symmetric {
+            return self.to_general_storage().write_dense(w);
+        }
+        let (rows, cols) = self.dims();
+        // write_grid 按行主序逐个取值, 沿 right 链前进即可, 不需要随机访问
+        let mut p = self.head.borrow().right.clone().unwrap();
+        write_grid(w, rows, cols, |r, c| {
+            let mut val = T::zero();
+            let next = {
+                let node = p.borrow();
+                if !Rc::ptr_eq(&p, &self.head) && node.row == r && node.col == c {
+                    val = node.value;
+                    node.right.clone()
+                } else {
+                    None
+                }
+            };
+            if let Some(next) = next {
+                p = next;
+            }
+            val
+        })
+    }
+}
+
+// 稠密打印的公共格式, SparseMatrix 和 DenseMatrix 共用; value 按行主序对每个位置 (1 起始) 调用一次
+pub(crate) fn write_grid<T: std::fmt::Display, W: Write>(
+    mut w: W,
+    rows: usize,
+    cols: usize,
+    mut value: impl FnMut(usize, usize) -> T,
+) -> std::io::Result<()> {
+    writeln!(w, "Matrix ({}x{}):", rows, cols)?;
+    for r in 1..=rows {
+        for c in 1..=cols {
+            write!(w, "{:4} ", value(r, c))?;
+        }
+        writeln!(w)?;
+    }
+    writeln!(w, "----------------------")?;
+    w.flush()
+}
@@ -0,0 +1,231 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::{Scalar, SparseMatrix};
+
+// 重排结果: perm[k] 为排到第 k + 1 位的原行/列号 (1 起始), 用 permute(&perm, &perm) 对称地作用到矩阵上
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ordering {
+    pub perm: Vec<usize>,
+    pub bandwidth_before: usize,
+    pub bandwidth_after: usize,
+    pub profile_before: usize,
+    pub profile_after: usize,
+}
+
+impl Ordering {
+    // 逆置换: inverse[old - 1] = new
+    pub fn inverse(&self) -> Vec<usize> {
+        let mut inv = vec![0; self.perm.len()];
+        for (k, &old) in self.perm.iter().enumerate() {
+            inv[old - 1] = k + 1;
+        }
+        inv
+    }
+}
+
+// 从一个结点出发做 BFS, 返回 (离心率, 最后一层结点); seen 用完后复原
+fn bfs_last_level(adj: &[Vec<usize>], root: usize, seen: &mut [bool]) -> (usize, Vec<usize>) {
+    let mut touched = vec![root];
+    seen[root] = true;
+    let mut level = vec![root];
+    let mut depth = 0;
+    loop {
+        let mut next = Vec::new();
+        for &v in &level {
+            for &u in &adj[v] {
+                if !seen[u] {
+                    seen[u] = true;
+                    touched.push(u);
+                    next.push(u);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        level = next;
+        depth += 1;
+    }
+    for v in touched {
+        seen[v] = false;
+    }
+    (depth, level)
+}
+
+// George-Liu 伪外围点: 反复跳到最后一层中度数最小的点, 直到离心率不再增大
+fn pseudo_peripheral(adj: &[Vec<usize>], start: usize, seen: &mut [bool]) -> usize {
+    let mut root = start;
+    let (mut ecc, mut last) = bfs_last_level(adj, root, seen);
+    loop {
+        let candidate = *last.iter().min_by_key(|&&u| (adj[u].len(), u)).unwrap();
+        let (e, l) = bfs_last_level(adj, candidate, seen);
+        if e <= ecc {
+            return root;
+        }
+        root = candidate;
+        ecc = e;
+        last = l;
+    }
+}
+
+fn reverse_cuthill_mckee(adj: &[Vec<usize>]) -> Vec<usize> {
+    let n = adj.len();
+    let mut visited = vec![false; n];
+    let mut scratch = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+
+    let mut starts: Vec<usize> = (0..n).collect();
+    starts.sort_by_key(|&i| (adj[i].len(), i));
+    for start in starts {
+        if visited[start] {
+            continue;
+        }
+        let root = pseudo_peripheral(adj, start, &mut scratch);
+        visited[root] = true;
+        let mut head = order.len();
+        order.push(root);
+        while head < order.len() {
+            let v = order[head];
+            head += 1;
+            let mut nbrs: Vec<usize> = adj[v].iter().copied().filter(|&u| !visited[u]).collect();
+            nbrs.sort_by_key(|&u| (adj[u].len(), u));
+            for u in nbrs {
+                visited[u] = true;
+                order.push(u);
+            }
+        }
+    }
+    order.reverse();
+    order
+}
+
+// 近似最小度 (AMD) 排序, 基于商图:
+//   变量 i 保存仍相邻的变量 vars[i] 和相邻的元素 elems[i];
+//   消去 p 时生成元素 p, 其结点集 L_p = vars[p] ∪ (elems[p] 中各元素的结点集) \ {p}, 被合并的元素随之吸收;
+//   度数取 AMD 的上界 min(剩余变量数 - 1, d_i + |L_p \ i|, |vars[i]| + |L_p \ i| + Σ |L_e \ L_p|)
+fn approximate_minimum_degree(adj: &[Vec<usize>]) -> Vec<usize> {
+    let n = adj.len();
+    let mut vars: Vec<Vec<usize>> = adj.to_vec();
+    let mut elems: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut members: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut eliminated = vec![false; n];
+    let mut absorbed = vec![false; n];
+    let mut degree: Vec<usize> = adj.iter().map(Vec::len).collect();
+
+    // mark[v] == stamp 表示 v 属于当前的 L_p; w_stamp[e] == stamp 时 w[e] 有效
+    let mut mark = vec![usize::MAX; n];
+    let mut w = vec![0usize; n];
+    let mut w_stamp = vec![usize::MAX; n];
+
+    let mut heap: BinaryHeap<Reverse<(usize, usize)>> = (0..n).map(|i| Reverse((degree[i], i))).collect();
+    let mut order = Vec::with_capacity(n);
+
+    for stamp in 0..n {
+        let p = loop {
+            let Reverse((d, v)) = heap.pop().expect("every variable is eliminated exactly once");
+            if !eliminated[v] && d == degree[v] {
+                break v;
+            }
+        };
+        order.push(p);
+        eliminated[p] = true;
+
+        let mut lp: Vec<usize> = Vec::new();
+        for &v in &vars[p] {
+            if mark[v] != stamp {
+                mark[v] = stamp;
+                lp.push(v);
+            }
+        }
+        for &e in &elems[p] {
+            for &v in &members[e] {
+                if v != p && mark[v] != stamp {
+                    mark[v] = stamp;
+                    lp.push(v);
+                }
+            }
+        }
+        for e in std::mem::take(&mut elems[p]) {
+            absorbed[e] = true;
+            members[e] = Vec::new();
+        }
+        vars[p] = Vec::new();
+
+        // w[e] = |L_e \ L_p|
+        for &i in &lp {
+            for &e in &elems[i] {
+                if absorbed[e] {
+                    continue;
+                }
+                if w_stamp[e] != stamp {
+                    w_stamp[e] = stamp;
+                    w[e] = members[e].len();
+                }
+                w[e] -= 1;
+            }
+        }
+
+        let remaining = n - stamp - 1;
+        for &i in &lp {
+            vars[i].retain(|&v| v != p && mark[v] != stamp);
+            elems[i].retain(|&e| !absorbed[e]);
+            let external: usize = elems[i].iter().map(|&e| w[e]).sum();
+            elems[i].push(p);
+
+            let bound = vars[i].len() + lp.len() - 1 + external;
+            let d = bound.min(degree[i] + lp.len() - 1).min(remaining.saturating_sub(1));
+            degree[i] = d;
+            heap.push(Reverse((d, i)));
+        }
+        members[p] = lp;
+    }
+    order
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    // A + A^T 的非零结构, 去掉对角线, 下标从 0 开始
+    fn symmetric_adjacency(&self) -> Vec<Vec<usize>> {
+        let (rows, cols) = self.dims();
+        if rows != cols {
+            panic!("Fill-reducing orderings require a square matrix");
+        }
+        let mut adj = vec![Vec::new(); rows];
+        for (r, c, _) in self.triplets() {
+            if r != c {
+                adj[r - 1].push(c - 1);
+                adj[c - 1].push(r - 1);
+            }
+        }
+        for list in &mut adj {
+            list.sort_unstable();
+            list.dedup();
+        }
+        adj
+    }
+
+    fn ordering_report(&self, order: Vec<usize>) -> Ordering {
+        let perm: Vec<usize> = order.into_iter().map(|i| i + 1).collect();
+        let before = self.stats();
+        let after = self.permute(&perm, &perm).stats();
+        Ordering {
+            perm,
+            bandwidth_before: before.lower_bandwidth.max(before.upper_bandwidth),
+            bandwidth_after: after.lower_bandwidth.max(after.upper_bandwidth),
+            profile_before: before.profile,
+            profile_after: after.profile,
+        }
+    }
+
+    // 逆 Cuthill-McKee: 减小带宽
+    pub fn rcm_ordering(&self) -> Ordering {
+        let order = reverse_cuthill_mckee(&self.symmetric_adjacency());
+        self.ordering_report(order)
+    }
+
+    // 近似最小度: 减少 Cholesky/LU 分解的填充
+    pub fn amd_ordering(&self) -> Ordering {
+        let order = approximate_minimum_degree(&self.symmetric_adjacency());
+        self.ordering_report(order)
+    }
+}
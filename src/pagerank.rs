@@ -0,0 +1,125 @@
+use crate::{Scalar, SparseMatrix};
+
+// 收敛判据: 相邻两次迭代的秩向量之差的 1-范数不超过 tol
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageRankOptions {
+    pub damping: f64,
+    pub tol: f64,
+    pub max_iter: usize,
+}
+
+impl Default for PageRankOptions {
+    fn default() -> Self {
+        PageRankOptions { damping: 0.85, tol: 1e-10, max_iter: 100 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRankResult {
+    pub ranks: Vec<f64>,
+    pub iterations: usize,
+    pub converged: bool,
+    // 最后一次迭代的变化量 (1-范数)
+    pub delta: f64,
+}
+
+// 约定: 邻接矩阵 A(i, j) 为从结点 j 指向结点 i 的链接权重, 即第 j 列是 j 的出链;
+// 按行存放出链的图先 transpose() 再调用
+impl<T: Scalar> SparseMatrix<T> {
+    fn check_link_graph(&self) -> usize {
+        let (rows, cols) = self.dims();
+        if rows != cols {
+            panic!("PageRank requires a square adjacency matrix");
+        }
+        if self.triplets().iter().any(|&(_, _, v)| v.to_f64() < 0.0) {
+            panic!("PageRank requires non-negative link weights");
+        }
+        rows
+    }
+
+    fn column_totals(&self) -> Vec<f64> {
+        let (_, cols) = self.dims();
+        let mut totals = vec![0.0; cols];
+        for (_, c, v) in self.triplets() {
+            totals[c - 1] += v.to_f64();
+        }
+        totals
+    }
+
+    // 列随机化: 每列除以列和, 得到随机游走的转移矩阵; 全零列 (悬挂结点) 保持为零
+    pub fn column_stochastic(&self) -> SparseMatrix<f64> {
+        let (rows, cols) = self.dims();
+        self.check_link_graph();
+        let totals = self.column_totals();
+        let entries = self.triplets().into_iter()
+            .map(|(r, c, v)| (r, c, v.to_f64() / totals[c - 1]));
+        SparseMatrix::from_row_major(rows, cols, entries)
+    }
+
+    // 没有出链的结点 (1 起始)
+    pub fn dangling_nodes(&self) -> Vec<usize> {
+        self.check_link_graph();
+        self.column_totals().iter().enumerate()
+            .filter(|&(_, &t)| t == 0.0)
+            .map(|(j, _)| j + 1)
+            .collect()
+    }
+
+    // 随机游走一步: 概率分布 x 经转移矩阵后的分布, 悬挂结点上的概率按 teleport 重新分配
+    fn walk_step(transition: &SparseMatrix<f64>, dangling: &[usize], x: &[f64], teleport: &[f64]) -> Vec<f64> {
+        let mut y = transition.mul_vec(x);
+        let lost: f64 = dangling.iter().map(|&j| x[j - 1]).sum();
+        y.iter_mut().zip(teleport).for_each(|(yi, ti)| *yi += lost * ti);
+        y
+    }
+
+    // 阻尼 PageRank: x = d·(M x + 悬挂质量·v) + (1 - d)·v, v 为均匀分布
+    pub fn pagerank(&self, opts: &PageRankOptions) -> PageRankResult {
+        let n = self.check_link_graph();
+        let teleport = vec![1.0 / n as f64; n];
+        self.pagerank_with_teleport(teleport, opts)
+    }
+
+    // 个性化 PageRank: 随机跳转 (和悬挂结点) 按 personalization 的比例落到各结点上
+    pub fn personalized_pagerank(&self, personalization: &[f64], opts: &PageRankOptions) -> PageRankResult {
+        let n = self.check_link_graph();
+        if personalization.len() != n {
+            panic!("Personalization vector has length {}, expected {}", personalization.len(), n);
+        }
+        if personalization.iter().any(|&p| p.is_nan() || p < 0.0) {
+            panic!("Personalization weights must be non-negative");
+        }
+        let total: f64 = personalization.iter().sum();
+        if total <= 0.0 {
+            panic!("Personalization vector must have a positive sum");
+        }
+        let teleport = personalization.iter().map(|&p| p / total).collect();
+        self.pagerank_with_teleport(teleport, opts)
+    }
+
+    fn pagerank_with_teleport(&self, teleport: Vec<f64>, opts: &PageRankOptions) -> PageRankResult {
+        if !(0.0..=1.0).contains(&opts.damping) {
+            panic!("Damping factor {} must lie in [0, 1]", opts.damping);
+        }
+        let n = teleport.len();
+        let transition = self.column_stochastic();
+        let dangling = self.dangling_nodes();
+
+        let mut result = PageRankResult { ranks: teleport.clone(), iterations: 0, converged: n == 0, delta: 0.0 };
+        while !result.converged && result.iterations < opts.max_iter {
+            let walked = Self::walk_step(&transition, &dangling, &result.ranks, &teleport);
+            let mut next: Vec<f64> = walked.iter().zip(&teleport)
+                .map(|(w, t)| opts.damping * w + (1.0 - opts.damping) * t)
+                .collect();
+            // 抵消舍入误差, 保持概率和为 1
+            let sum: f64 = next.iter().sum();
+            next.iter_mut().for_each(|v| *v /= sum);
+
+            result.delta = next.iter().zip(&result.ranks).map(|(a, b)| (a - b).abs()).sum();
+            result.ranks = next;
+            result.iterations += 1;
+            result.converged = result.delta <= opts.tol;
+        }
+        result
+    }
+}
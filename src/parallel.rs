@@ -0,0 +1,129 @@
+use std::ops::Range;
+use std::panic;
+use std::thread;
+
+use crate::{Scalar, SparseMatrix};
+
+// 把 0..n 切成至多 threads 段连续区间, 每段交给一个工作线程, 结果按区间顺序返回
+fn run_chunks<R, F>(n: usize, threads: usize, work: F) -> Vec<R>
+where
+    R: Send,
+    F: Fn(Range<usize>) -> R + Sync,
+{
+    let threads = threads.clamp(1, n.max(1));
+    let chunk = n.div_ceil(threads);
+    thread::scope(|s| {
+        let work = &work;
+        let handles: Vec<_> = (0..threads)
+            .map(|t| {
+                let range = (t * chunk).min(n)..((t + 1) * chunk).min(n);
+                s.spawn(move || work(range))
+            })
+            .collect();
+        handles.into_iter()
+            .map(|h| h.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    })
+}
+
+// 链表结点不能跨线程共享, 所以先在调用线程上把各行拷贝成普通数组, 工作线程只读这些快照,
+// 最后再由调用线程把各段结果串成一个矩阵
+impl<T: Scalar + Send + Sync> SparseMatrix<T> {
+    pub fn par_add(&self, other: &SparseMatrix<T>, threads: usize) -> SparseMatrix<T> {
+        let (rows, cols) = self.dims();
+        if other.dims() != (rows, cols) {
+            panic!("Dimensions mismatch for addition");
+        }
+        let lines_a = self.row_lines();
+        let lines_b = other.row_lines();
+
+        let chunks = run_chunks(rows, threads, |range| {
+            let mut out = Vec::new();
+            for row in range.start + 1..range.end + 1 {
+                let (a, b) = (&lines_a[row], &lines_b[row]);
+                let (mut i, mut j) = (0, 0);
+                while i < a.len() || j < b.len() {
+                    let key_a = a.get(i).map_or(usize::MAX, |e| e.0);
+                    let key_b = b.get(j).map_or(usize::MAX, |e| e.0);
+                    if key_a < key_b {
+                        out.push((row, key_a, a[i].1));
+                        i += 1;
+                    } else if key_b < key_a {
+                        out.push((row, key_b, b[j].1));
+                        j += 1;
+                    } else {
+                        let val = a[i].1 + b[j].1;
+                        if !val.is_zero() {
+                            out.push((row, key_a, val));
+                        }
+                        i += 1;
+                        j += 1;
+                    }
+                }
+            }
+            out
+        });
+
+        let result = SparseMatrix::from_row_major(rows, cols, chunks.into_iter().flatten());
+        result.debug_validate("par_add");
+        result
+    }
+
+    pub fn par_multiply(&self, other: &SparseMatrix<T>, threads: usize) -> SparseMatrix<T> {
+        let (r_a, c_a) = self.dims();
+        let (r_b, c_b) = other.dims();
+        if c_a != r_b {
+            panic!("Dimensions mismatch for multiplication");
+        }
+        let lines_a = self.row_lines();
+        let lines_b = other.row_lines();
+
+        let chunks = run_chunks(r_a, threads, |range| {
+            let mut out = Vec::new();
+            // 稠密累加器 + 已触及列表, 每行结束后只清理触及过的列
+            let mut acc = vec![T::zero(); c_b + 1];
+            let mut touched = vec![false; c_b + 1];
+            let mut cols: Vec<usize> = Vec::new();
+            for (row, line) in (range.start + 1..).zip(&lines_a[range.start + 1..range.end + 1]) {
+                for &(k, a) in line {
+                    for &(j, b) in &lines_b[k] {
+                        if !touched[j] {
+                            touched[j] = true;
+                            cols.push(j);
+                        }
+                        acc[j] += a * b;
+                    }
+                }
+                cols.sort_unstable();
+                for &j in &cols {
+                    if !acc[j].is_zero() {
+                        out.push((row, j, acc[j]));
+                    }
+                    acc[j] = T::zero();
+                    touched[j] = false;
+                }
+                cols.clear();
+            }
+            out
+        });
+
+        let product = SparseMatrix::from_row_major(r_a, c_b, chunks.into_iter().flatten());
+        product.debug_validate("par_multiply");
+        product
+    }
+
+    pub fn par_mul_vec(&self, x: &[T], threads: usize) -> Vec<T> {
+        let (rows, cols) = self.dims();
+        if x.len() != cols {
+            panic!("Dimensions mismatch for matrix-vector product");
+        }
+        let lines = self.row_lines();
+
+        let chunks = run_chunks(rows, threads, |range| {
+            lines[range.start + 1..range.end + 1].iter()
+                .map(|line| line.iter().fold(T::zero(), |sum, &(c, v)| sum + v * x[c - 1]))
+                .collect::<Vec<T>>()
+        });
+        chunks.concat()
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{Scalar, SparseMatrix};
+
+// 一行 (或一列) 中的最大值及其 1 起始下标, 未存储的位置按 0 参与比较
+fn line_max<T: Scalar>(entries: &[(usize, T)], n: usize) -> Option<(usize, T)> {
+    if n == 0 {
+        return None;
+    }
+    let mut best: Option<(usize, T)> = None;
+    for &(i, v) in entries {
+        if best.is_none_or(|(_, b)| v > b) {
+            best = Some((i, v));
+        }
+    }
+    if entries.len() < n {
+        // 第一个未存储的位置即隐式零元
+        let gap = entries.iter().enumerate()
+            .find(|&(k, &(i, _))| i != k + 1)
+            .map_or(entries.len() + 1, |(k, _)| k + 1);
+        match best {
+            Some((i, b)) if b > T::zero() || (b.is_zero() && i < gap) => {}
+            _ => best = Some((gap, T::zero())),
+        }
+    }
+    best
+}
+
+// 取最大值; 元素类型只有 PartialOrd, 不能直接用 Iterator::max
+fn max_or_zero<T: Scalar>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::zero(), |m, v| if v > m { v } else { m })
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    // 各行之和, 第 i 个元素对应第 i + 1 行
+    pub fn row_sums(&self) -> Vec<T> {
+        let (rows, _) = self.dims();
+        let mut sums = vec![T::zero(); rows];
+        for (r, _, v) in self.triplets() {
+            sums[r - 1] += v;
+        }
+        sums
+    }
+
+    pub fn col_sums(&self) -> Vec<T> {
+        let (_, cols) = self.dims();
+        let mut sums = vec![T::zero(); cols];
+        for (_, c, v) in self.col_major_triplets() {
+            sums[c - 1] += v;
+        }
+        sums
+    }
+
+    // 各行最大值及其列号 (并列时取最小列号); 矩阵没有列时为 None
+    pub fn row_max(&self) -> Vec<Option<(usize, T)>> {
+        let (_, cols) = self.dims();
+        self.row_lines().iter().skip(1).map(|line| line_max(line, cols)).collect()
+    }
+
+    pub fn col_max(&self) -> Vec<Option<(usize, T)>> {
+        let (rows, _) = self.dims();
+        self.col_lines().iter().skip(1).map(|line| line_max(line, rows)).collect()
+    }
+
+    // 主对角线, 长度为 min(rows, cols)
+    pub fn diagonal(&self) -> Vec<T> {
+        let (rows, cols) = self.dims();
+        let mut diag = vec![T::zero(); rows.min(cols)];
+        for (r, c, v) in self.triplets() {
+            if r == c {
+                diag[r - 1] += v;
+            }
+        }
+        diag
+    }
+
+    pub fn trace(&self) -> T {
+        self.diagonal().into_iter().fold(T::zero(), |s, v| s + v)
+    }
+
+    // 1-范数: 列绝对值和的最大值
+    pub fn norm_1(&self) -> T {
+        let (_, cols) = self.dims();
+        let mut sums = vec![T::zero(); cols + 1];
+        for (_, c, v) in self.col_major_triplets() {
+            sums[c] += v.abs();
+        }
+        max_or_zero(sums)
+    }
+
+    // 无穷范数: 行绝对值和的最大值
+    pub fn norm_inf(&self) -> T {
+        let (rows, _) = self.dims();
+        let mut sums = vec![T::zero(); rows + 1];
+        for (r, _, v) in self.triplets() {
+            sums[r] += v.abs();
+        }
+        max_or_zero(sums)
+    }
+
+    pub fn norm_frobenius(&self) -> f64 {
+        self.triplets().iter()
+            .map(|&(_, _, v)| v.to_f64() * v.to_f64())
+            .sum::<f64>()
+            .sqrt()
+    }
+}
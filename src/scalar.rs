@@ -0,0 +1,135 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+use std::str::FromStr;
+
+// 矩阵元素类型: 整数矩阵用 i32 (默认), 数值分解用 f64
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Default
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + From<i8>
+    + FromStr
+{
+    // 整数类型为 true; 决定 Matrix Market 文件的 field 等
+    const INTEGRAL: bool;
+
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn one() -> Self {
+        Self::from(1)
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn abs(self) -> Self {
+        if self < Self::zero() { -self } else { self }
+    }
+
+    // 能精确表示 x 时返回 Some: 整数类型要求 x 是范围内的整数, 浮点类型直接转换
+    fn from_f64_exact(x: f64) -> Option<Self>;
+
+    // 换成 f64 参与数值计算 (如 PageRank); i64 超出 2^53 的部分会丢失精度
+    fn to_f64(self) -> f64;
+
+    // 溢出时返回 None; 浮点数溢出得到 inf, 不算错误, 默认实现直接计算
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn checked_neg(self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+macro_rules! integer_checked_ops {
+    ($t:ty) => {
+        fn checked_add(self, rhs: Self) -> Option<Self> {
+            <$t>::checked_add(self, rhs)
+        }
+
+        fn checked_sub(self, rhs: Self) -> Option<Self> {
+            <$t>::checked_sub(self, rhs)
+        }
+
+        fn checked_mul(self, rhs: Self) -> Option<Self> {
+            <$t>::checked_mul(self, rhs)
+        }
+
+        fn checked_neg(self) -> Option<Self> {
+            <$t>::checked_neg(self)
+        }
+    };
+}
+
+impl Scalar for i32 {
+    const INTEGRAL: bool = true;
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn from_f64_exact(x: f64) -> Option<Self> {
+        (x.fract() == 0.0 && x >= i32::MIN as f64 && x <= i32::MAX as f64).then_some(x as i32)
+    }
+
+    integer_checked_ops!(i32);
+}
+
+impl Scalar for i64 {
+    const INTEGRAL: bool = true;
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    // i64::MAX 不能精确表示为 f64, 上界取开区间 2^63
+    fn from_f64_exact(x: f64) -> Option<Self> {
+        (x.fract() == 0.0 && x >= i64::MIN as f64 && x < 9_223_372_036_854_775_808.0).then_some(x as i64)
+    }
+
+    integer_checked_ops!(i64);
+}
+
+impl Scalar for f32 {
+    const INTEGRAL: bool = false;
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn from_f64_exact(x: f64) -> Option<Self> {
+        Some(x as f32)
+    }
+}
+
+impl Scalar for f64 {
+    const INTEGRAL: bool = false;
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64_exact(x: f64) -> Option<Self> {
+        Some(x)
+    }
+}
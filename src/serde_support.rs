@@ -0,0 +1,67 @@
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Scalar, SparseMatrix, TripletError};
+
+// 序列化形式: 维数 + 行主序三元组; 对称存储时带 symmetric 标记, 且只给出下三角
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "SparseMatrix")]
+struct Repr<T> {
+    rows: usize,
+    cols: usize,
+    #[serde(default, skip_serializing_if = "is_false")]
+    symmetric: bool,
+    triplets: Vec<(usize, usize, T)>,
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+// 序列化的输出总是严格按行主序排列, 反序列化时照此要求, 不替输入排序
+fn check_entries<T>(repr: &Repr<T>) -> Result<(), String> {
+    let (rows, cols) = (repr.rows, repr.cols);
+    if repr.symmetric && rows != cols {
+        return Err(format!("symmetric storage requires a square matrix, got {}x{}", rows, cols));
+    }
+    let mut prev: Option<(usize, usize)> = None;
+    for &(row, col, _) in &repr.triplets {
+        if row == 0 || row > rows || col == 0 || col > cols {
+            return Err(TripletError::OutOfBounds { row, col, rows, cols }.to_string());
+        }
+        if repr.symmetric && row < col {
+            return Err(format!("entry ({}, {}) lies above the diagonal of a symmetric-storage matrix", row, col));
+        }
+        match prev {
+            Some(p) if p == (row, col) => return Err(TripletError::Duplicate { row, col }.to_string()),
+            Some(p) if p > (row, col) => {
+                return Err(format!("entry ({}, {}) follows ({}, {}): entries must be in row-major order", row, col, p.0, p.1));
+            }
+            _ => prev = Some((row, col)),
+        }
+    }
+    Ok(())
+}
+
+impl<T: Scalar + Serialize> Serialize for SparseMatrix<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (rows, cols) = self.dims();
+        let triplets = if self.symmetric { self.stored_triplets() } else { self.triplets() };
+        Repr { rows, cols, symmetric: self.symmetric, triplets }.serialize(serializer)
+    }
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for SparseMatrix<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = Repr::<T>::deserialize(deserializer)?;
+        check_entries(&repr).map_err(D::Error::custom)?;
+        let entries = repr.triplets.into_iter().filter(|(_, _, v)| !v.is_zero());
+        let matrix = if repr.symmetric {
+            SparseMatrix::from_lower_row_major(repr.rows, entries)
+        } else {
+            SparseMatrix::from_row_major(repr.rows, repr.cols, entries)
+        };
+        matrix.debug_validate("deserialize");
+        Ok(matrix)
+    }
+}
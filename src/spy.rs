@@ -0,0 +1,151 @@
+use std::io::{self, Write};
+
+use crate::{Scalar, SparseMatrix};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpyStyle {
+    // 每个字符一个格子, 按格内非零元比例取 " .:*#"
+    Ascii,
+    // 每个字符 2x4 个盲文点, 分辨率是 Ascii 的 8 倍
+    Braille,
+}
+
+// 盲文字符 U+2800 起, 点 (x, y) 对应的位
+const BRAILLE_BITS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+// 降采样后的网格: counts[y * width + x] 为落在该格内的非零元个数
+struct SpyGrid {
+    width: usize,
+    height: usize,
+    counts: Vec<usize>,
+    // 每个格子覆盖的行数 / 列数, 用来算填充比例
+    row_span: Vec<usize>,
+    col_span: Vec<usize>,
+}
+
+impl SpyGrid {
+    // 格子覆盖的元素个数可能超出 usize, 面积用浮点数计算
+    fn fill(&self, x: usize, y: usize) -> f64 {
+        let area = self.row_span[y] as f64 * self.col_span[x] as f64;
+        if area == 0.0 { 0.0 } else { self.counts[y * self.width + x] as f64 / area }
+    }
+}
+
+// 第 i (1 起始) 个下标落在 cells 个格子中的哪一格: floor((i - 1) * cells / n), 用 u128 防止溢出
+fn cell_of(i: usize, n: usize, cells: usize) -> usize {
+    ((i - 1) as u128 * cells as u128 / n as u128) as usize
+}
+
+// 每个格子覆盖的下标个数: 第 k 格从 ceil(k * n / cells) 开始, 直接算出来而不逐个下标统计
+fn cell_spans(n: usize, cells: usize) -> Vec<usize> {
+    let start = |k: usize| ((k as u128 * n as u128).div_ceil(cells as u128)) as usize;
+    (0..cells).map(|k| start(k + 1) - start(k)).collect()
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    // 网格不会比矩阵本身更细; 耗时只与网格大小和非零元个数有关, 与维数无关
+    fn spy_grid(&self, width: usize, height: usize) -> SpyGrid {
+        let (rows, cols) = self.dims();
+        let width = width.clamp(1, cols.max(1));
+        let height = height.clamp(1, rows.max(1));
+
+        let mut counts = vec![0; width * height];
+        for (r, c, _) in self.iter() {
+            counts[cell_of(r, rows, height) * width + cell_of(c, cols, width)] += 1;
+        }
+        let row_span = cell_spans(rows, height);
+        let col_span = cell_spans(cols, width);
+        SpyGrid { width, height, counts, row_span, col_span }
+    }
+
+    // 把非零元分布画成至多 width x height 个字符, 带边框
+    pub fn spy(&self, width: usize, height: usize, style: SpyStyle) -> String {
+        let mut lines = Vec::new();
+        let inner_width = match style {
+            SpyStyle::Ascii => {
+                let grid = self.spy_grid(width, height);
+                for y in 0..grid.height {
+                    let line: String = (0..grid.width)
+                        .map(|x| match grid.fill(x, y) {
+                            0.0 => ' ',
+                            f if f < 0.25 => '.',
+                            f if f < 0.5 => ':',
+                            f if f < 1.0 => '*',
+                            _ => '#',
+                        })
+                        .collect();
+                    lines.push(line);
+                }
+                grid.width
+            }
+            SpyStyle::Braille => {
+                let grid = self.spy_grid(width * 2, height * 4);
+                let (w, h) = (grid.width.div_ceil(2), grid.height.div_ceil(4));
+                for cy in 0..h {
+                    let line: String = (0..w)
+                        .map(|cx| {
+                            let mut bits = 0;
+                            for (dx, column) in BRAILLE_BITS.iter().enumerate() {
+                                for (dy, bit) in column.iter().enumerate() {
+                                    let (x, y) = (cx * 2 + dx, cy * 4 + dy);
+                                    if x < grid.width && y < grid.height && grid.counts[y * grid.width + x] > 0 {
+                                        bits |= bit;
+                                    }
+                                }
+                            }
+                            char::from_u32(0x2800 + bits).unwrap_or(' ')
+                        })
+                        .collect();
+                    lines.push(line);
+                }
+                w
+            }
+        };
+
+        let border = format!("+{}+", "-".repeat(inner_width));
+        let mut out = String::new();
+        out.push_str(&border);
+        out.push('\n');
+        for line in lines {
+            out.push('|');
+            out.push_str(&line);
+            out.push_str("|\n");
+        }
+        out.push_str(&border);
+        out.push('\n');
+        out
+    }
+
+    // 以 SVG 输出非零元分布, 网格至多 width x height 格, 格子颜色深浅表示填充比例
+    pub fn write_spy_svg<W: Write>(&self, mut w: W, width: usize, height: usize) -> io::Result<()> {
+        const CELL_PX: usize = 4;
+        let (rows, cols) = self.dims();
+        let grid = self.spy_grid(width, height);
+        writeln!(
+            w,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+            grid.width * CELL_PX,
+            grid.height * CELL_PX,
+            grid.width,
+            grid.height
+        )?;
+        writeln!(w, "<title>{}x{} sparse matrix, nnz = {}</title>", rows, cols, grid.counts.iter().sum::<usize>())?;
+        writeln!(w, r#"<rect width="{}" height="{}" fill="white"/>"#, grid.width, grid.height)?;
+        for y in 0..grid.height {
+            for x in 0..grid.width {
+                let fill = grid.fill(x, y);
+                if fill > 0.0 {
+                    writeln!(
+                        w,
+                        r#"<rect x="{}" y="{}" width="1" height="1" fill="black" fill-opacity="{:.3}"/>"#,
+                        x,
+                        y,
+                        0.3 + 0.7 * fill.min(1.0)
+                    )?;
+                }
+            }
+        }
+        writeln!(w, "</svg>")?;
+        w.flush()
+    }
+}
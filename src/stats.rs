@@ -0,0 +1,134 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::mem::size_of;
+
+use crate::{MatrixNode, Scalar, SparseMatrix};
+
+// 每个结点是一次 Rc<RefCell<MatrixNode>> 分配: 强/弱引用计数 + RefCell 借用标记 + 结点本身
+const fn node_bytes<T>() -> usize {
+    size_of::<RefCell<MatrixNode<T>>>() + 2 * size_of::<usize>()
+}
+
+#[derive(Debug, Clone)]
+pub struct MatrixStats {
+    pub rows: usize,
+    pub cols: usize,
+    pub nnz: usize,
+    pub density: f64,
+    // row_nnz_histogram[k] = 恰有 k 个非零元的行数
+    pub row_nnz_histogram: Vec<usize>,
+    pub col_nnz_histogram: Vec<usize>,
+    pub lower_bandwidth: usize,
+    pub upper_bandwidth: usize,
+    // 下三角包络: 各行 (行号 - 该行最左非零列号) 之和
+    pub profile: usize,
+    pub is_square: bool,
+    pub is_pattern_symmetric: bool,
+    pub is_symmetric: bool,
+    // 对称存储时 node_bytes 只计下三角的结点
+    pub is_symmetric_storage: bool,
+    pub node_bytes: usize,
+    pub header_bytes: usize,
+}
+
+impl MatrixStats {
+    pub fn total_bytes(&self) -> usize {
+        self.node_bytes + self.header_bytes
+    }
+}
+
+fn histogram(counts: &[usize]) -> Vec<usize> {
+    let max = counts.iter().copied().max().unwrap_or(0);
+    let mut hist = vec![0; max + 1];
+    for &k in counts {
+        hist[k] += 1;
+    }
+    hist
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    pub fn stats(&self) -> MatrixStats {
+        let (rows, cols) = self.dims();
+        let entries = self.triplets();
+        let nnz = entries.len();
+
+        let mut row_counts = vec![0; rows];
+        let mut col_counts = vec![0; cols];
+        let mut row_first: Vec<Option<usize>> = vec![None; rows];
+        let mut lower_bandwidth = 0;
+        let mut upper_bandwidth = 0;
+        for &(r, c, _) in &entries {
+            row_counts[r - 1] += 1;
+            col_counts[c - 1] += 1;
+            if row_first[r - 1].is_none() {
+                row_first[r - 1] = Some(c);
+            }
+            if r > c {
+                lower_bandwidth = lower_bandwidth.max(r - c);
+            } else {
+                upper_bandwidth = upper_bandwidth.max(c - r);
+            }
+        }
+        let profile = row_first.iter().enumerate()
+            .filter_map(|(i, first)| first.map(|c| (i + 1).saturating_sub(c)))
+            .sum();
+
+        // 列主序的 (col, row) 恰好是转置矩阵的行主序
+        let is_square = rows == cols;
+        let transposed: Vec<(usize, usize, T)> = self.col_major_triplets()
+            .into_iter()
+            .map(|(r, c, v)| (c, r, v))
+            .collect();
+        let is_pattern_symmetric = is_square
+            && entries.iter().zip(&transposed).all(|(a, b)| (a.0, a.1) == (b.0, b.1));
+        let is_symmetric = is_pattern_symmetric && entries == transposed;
+
+        let density = if rows == 0 || cols == 0 { 0.0 } else { nnz as f64 / (rows as f64 * cols as f64) };
+
+        MatrixStats {
+            rows,
+            cols,
+            nnz,
+            density,
+            row_nnz_histogram: histogram(&row_counts),
+            col_nnz_histogram: histogram(&col_counts),
+            lower_bandwidth,
+            upper_bandwidth,
+            profile,
+            is_square,
+            is_pattern_symmetric,
+            is_symmetric,
+            is_symmetric_storage: self.symmetric,
+            node_bytes: self.stored_triplets().len() * node_bytes::<T>(),
+            header_bytes: node_bytes::<T>() + size_of::<SparseMatrix<T>>(),
+        }
+    }
+}
+
+impl fmt::Display for MatrixStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Matrix {}x{}, nnz = {}, density = {:.4}%", self.rows, self.cols, self.nnz, self.density * 100.0)?;
+        writeln!(f, "  bandwidth: lower {}, upper {}; profile {}", self.lower_bandwidth, self.upper_bandwidth, self.profile)?;
+        writeln!(
+            f,
+            "  square: {}, pattern symmetric: {}, symmetric: {}",
+            self.is_square, self.is_pattern_symmetric, self.is_symmetric
+        )?;
+        for (label, hist) in [("row", &self.row_nnz_histogram), ("col", &self.col_nnz_histogram)] {
+            write!(f, "  {} nnz histogram:", label)?;
+            for (k, &count) in hist.iter().enumerate().filter(|&(_, &count)| count > 0) {
+                write!(f, " {}:{}", k, count)?;
+            }
+            writeln!(f)?;
+        }
+        write!(
+            f,
+            "  memory: {} bytes in nodes, {} bytes in headers, {} bytes total",
+            self.node_bytes, self.header_bytes, self.total_bytes()
+        )?;
+        if self.is_symmetric_storage {
+            write!(f, " (symmetric storage)")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{Link, MatrixNode, Scalar, SparseMatrix};
+
+impl<T: Scalar> SparseMatrix<T> {
+    // 沿 right 链访问每个结点
+    fn for_each_node_mut<F: FnMut(&mut MatrixNode<T>)>(&self, mut f: F) {
+        let mut p = self.head.borrow().right.clone().unwrap();
+        while !Rc::ptr_eq(&p, &self.head) {
+            f(&mut p.borrow_mut());
+            let next = p.borrow().right.clone().unwrap();
+            p = next;
+        }
+    }
+
+    // 把满足条件的结点同时从 right 链和 down 链上摘下
+    pub(crate) fn unlink_where<F: Fn(&MatrixNode<T>) -> bool>(&self, pred: F) {
+        let mut prev = self.head.clone();
+        loop {
+            let curr = prev.borrow().right.clone().unwrap();
+            if Rc::ptr_eq(&curr, &self.head) { break; }
+            if pred(&curr.borrow()) {
+                let after = curr.borrow().right.clone();
+                prev.borrow_mut().right = after;
+            } else {
+                prev = curr;
+            }
+        }
+
+        let mut prev = self.head.clone();
+        loop {
+            let curr = prev.borrow().down.clone().unwrap();
+            if Rc::ptr_eq(&curr, &self.head) { break; }
+            if pred(&curr.borrow()) {
+                let after = curr.borrow().down.clone();
+                prev.borrow_mut().down = after;
+            } else {
+                prev = curr;
+            }
+        }
+    }
+
+    // right 链上最后一个 (row, col) 小于给定坐标的结点, 可能是头结点
+    pub(crate) fn right_predecessor(&self, row: usize, col: usize) -> Link<T> {
+        let mut prev = self.head.clone();
+        loop {
+            let next = prev.borrow().right.clone().unwrap();
+            if Rc::ptr_eq(&next, &self.head) || (next.borrow().row, next.borrow().col) >= (row, col) {
+                return prev;
+            }
+            prev = next;
+        }
+    }
+
+    // down 链按 (col, row) 排序
+    pub(crate) fn down_predecessor(&self, row: usize, col: usize) -> Link<T> {
+        let mut prev = self.head.clone();
+        loop {
+            let next = prev.borrow().down.clone().unwrap();
+            if Rc::ptr_eq(&next, &self.head) || (next.borrow().col, next.borrow().row) >= (col, row) {
+                return prev;
+            }
+            prev = next;
+        }
+    }
+
+    pub(crate) fn check_position(&self, row: usize, col: usize) {
+        let (rows, cols) = self.dims();
+        if row == 0 || row > rows || col == 0 || col > cols {
+            panic!("Position ({}, {}) out of bounds for {}x{} matrix", row, col, rows, cols);
+        }
+    }
+
+    // 对称存储只保存下三角, 上三角位置换到镜像处
+    pub(crate) fn storage_position(&self, row: usize, col: usize) -> (usize, usize) {
+        if self.symmetric && row < col { (col, row) } else { (row, col) }
+    }
+
+    fn check_general_storage(&self, op: &str) {
+        if self.symmetric {
+            panic!("{} is not supported in symmetric storage; convert with to_general_storage() first", op);
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self.check_position(row, col);
+        let (row, col) = self.storage_position(row, col);
+        let next = self.right_predecessor(row, col).borrow().right.clone().unwrap();
+        let node = next.borrow();
+        if !Rc::ptr_eq(&next, &self.head) && node.row == row && node.col == col { node.value } else { T::zero() }
+    }
+
+    // 写入一个元素: 已有结点则改值, 否则同时插入两条链表; 写入 0 会删除结点
+    // 对称存储时 (row, col) 与 (col, row) 同时改变
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        self.check_position(row, col);
+        let (row, col) = self.storage_position(row, col);
+        let prev = self.right_predecessor(row, col);
+        let next = prev.borrow().right.clone().unwrap();
+        let exists = !Rc::ptr_eq(&next, &self.head) && {
+            let node = next.borrow();
+            node.row == row && node.col == col
+        };
+
+        if exists {
+            if value.is_zero() {
+                self.unlink_where(|node| node.row == row && node.col == col);
+            } else {
+                next.borrow_mut().value = value;
+            }
+            return;
+        }
+        if value.is_zero() {
+            return;
+        }
+
+        let node = Rc::new(RefCell::new(MatrixNode::new(row, col, value)));
+        node.borrow_mut().right = Some(next);
+        prev.borrow_mut().right = Some(node.clone());
+
+        let prev = self.down_predecessor(row, col);
+        let after = prev.borrow().down.clone();
+        node.borrow_mut().down = after;
+        prev.borrow_mut().down = Some(node);
+    }
+
+    // 在第 at 行之前插入一个空行, at = rows + 1 时追加到末尾
+    pub fn insert_row(&mut self, at: usize) {
+        self.check_general_storage("insert_row");
+        let (rows, _) = self.dims();
+        if at == 0 || at > rows + 1 {
+            panic!("Row insertion point {} out of bounds (valid positions are 1..={})", at, rows + 1);
+        }
+        self.for_each_node_mut(|node| if node.row >= at { node.row += 1 });
+        self.head.borrow_mut().row += 1;
+    }
+
+    pub fn delete_row(&mut self, i: usize) {
+        self.check_general_storage("delete_row");
+        let (rows, _) = self.dims();
+        if i == 0 || i > rows {
+            panic!("Row index {} out of bounds (valid indices are 1..={})", i, rows);
+        }
+        self.unlink_where(|node| node.row == i);
+        self.for_each_node_mut(|node| if node.row > i { node.row -= 1 });
+        self.head.borrow_mut().row -= 1;
+    }
+
+    // 在第 at 列之前插入一个空列, at = cols + 1 时追加到末尾
+    pub fn insert_col(&mut self, at: usize) {
+        self.check_general_storage("insert_col");
+        let (_, cols) = self.dims();
+        if at == 0 || at > cols + 1 {
+            panic!("Column insertion point {} out of bounds (valid positions are 1..={})", at, cols + 1);
+        }
+        self.for_each_node_mut(|node| if node.col >= at { node.col += 1 });
+        self.head.borrow_mut().col += 1;
+    }
+
+    pub fn delete_col(&mut self, j: usize) {
+        self.check_general_storage("delete_col");
+        let (_, cols) = self.dims();
+        if j == 0 || j > cols {
+            panic!("Column index {} out of bounds (valid indices are 1..={})", j, cols);
+        }
+        self.unlink_where(|node| node.col == j);
+        self.for_each_node_mut(|node| if node.col > j { node.col -= 1 });
+        self.head.borrow_mut().col -= 1;
+    }
+}
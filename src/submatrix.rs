@@ -0,0 +1,108 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::{Scalar, SparseMatrix};
+
+// 把 1 起始的范围换算成 (起点, 长度)
+fn resolve_range<R: RangeBounds<usize>>(range: R, n: usize, what: &str) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 1,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e,
+        Bound::Excluded(&e) => e.saturating_sub(1),
+        Bound::Unbounded => n,
+    };
+    if start == 0 || end > n {
+        panic!("{} range out of bounds (valid indices are 1..={})", what, n);
+    }
+    (start, (end + 1).saturating_sub(start))
+}
+
+fn check_indices(indices: &[usize], n: usize, what: &str) {
+    if let Some(&bad) = indices.iter().find(|&&i| i == 0 || i > n) {
+        panic!("{} index {} out of bounds (valid indices are 1..={})", what, bad, n);
+    }
+}
+
+fn check_permutation(perm: &[usize], n: usize, what: &str) {
+    if perm.len() != n {
+        panic!("{} permutation has length {}, expected {}", what, perm.len(), n);
+    }
+    check_indices(perm, n, what);
+    let mut seen = vec![false; n + 1];
+    for &i in perm {
+        if seen[i] {
+            panic!("{} permutation repeats index {}", what, i);
+        }
+        seen[i] = true;
+    }
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    // 子矩阵: 行列范围均为 1 起始, 结果从 (1,1) 重新编号
+    pub fn submatrix<R, C>(&self, row_range: R, col_range: C) -> SparseMatrix<T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (rows, cols) = self.dims();
+        let (r0, n_rows) = resolve_range(row_range, rows, "Row");
+        let (c0, n_cols) = resolve_range(col_range, cols, "Column");
+
+        let entries = self.triplets().into_iter()
+            .filter(|&(r, c, _)| r >= r0 && r < r0 + n_rows && c >= c0 && c < c0 + n_cols)
+            .map(|(r, c, v)| (r - r0 + 1, c - c0 + 1, v));
+        SparseMatrix::from_row_major(n_rows, n_cols, entries)
+    }
+
+    // 按任意行/列下标集合取元素: 结果 (i, j) = A(row_idx[i], col_idx[j]), 下标可重复
+    pub fn select(&self, row_idx: &[usize], col_idx: &[usize]) -> SparseMatrix<T> {
+        let (rows, cols) = self.dims();
+        check_indices(row_idx, rows, "Row");
+        check_indices(col_idx, cols, "Column");
+
+        let mut row_lists: Vec<Vec<(usize, T)>> = vec![Vec::new(); rows + 1];
+        for (r, c, v) in self.triplets() {
+            row_lists[r].push((c, v));
+        }
+        // 旧列号 -> 新列号 (升序)
+        let mut col_map: Vec<Vec<usize>> = vec![Vec::new(); cols + 1];
+        for (j, &c) in col_idx.iter().enumerate() {
+            col_map[c].push(j + 1);
+        }
+
+        let mut entries = Vec::new();
+        let mut buf: Vec<(usize, T)> = Vec::new();
+        for (i, &r) in row_idx.iter().enumerate() {
+            buf.clear();
+            for &(c, v) in &row_lists[r] {
+                buf.extend(col_map[c].iter().map(|&nc| (nc, v)));
+            }
+            buf.sort_by_key(|&(nc, _)| nc);
+            entries.extend(buf.iter().map(|&(nc, v)| (i + 1, nc, v)));
+        }
+        SparseMatrix::from_row_major(row_idx.len(), col_idx.len(), entries)
+    }
+
+    // 行列置换 P·A·Q: 结果 (i, j) = A(row_perm[i], col_perm[j])
+    pub fn permute(&self, row_perm: &[usize], col_perm: &[usize]) -> SparseMatrix<T> {
+        let (rows, cols) = self.dims();
+        check_permutation(row_perm, rows, "Row");
+        check_permutation(col_perm, cols, "Column");
+        self.select(row_perm, col_perm)
+    }
+
+    pub fn permute_rows(&self, row_perm: &[usize]) -> SparseMatrix<T> {
+        let (_, cols) = self.dims();
+        let identity: Vec<usize> = (1..=cols).collect();
+        self.permute(row_perm, &identity)
+    }
+
+    pub fn permute_cols(&self, col_perm: &[usize]) -> SparseMatrix<T> {
+        let (rows, _) = self.dims();
+        let identity: Vec<usize> = (1..=rows).collect();
+        self.permute(&identity, col_perm)
+    }
+}
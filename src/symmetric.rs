@@ -0,0 +1,74 @@
+use crate::{Scalar, SparseMatrix};
+
+impl<T: Scalar> SparseMatrix<T> {
+    // 由按行主序排好的下三角三元组构造对称存储的矩阵
+    pub(crate) fn from_lower_row_major<I>(n: usize, entries: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, T)>,
+    {
+        let mut matrix = SparseMatrix::from_row_major(n, n, entries);
+        matrix.symmetric = true;
+        matrix
+    }
+
+    // 下三角部分 (含对角线)
+    pub fn lower(&self) -> SparseMatrix<T> {
+        let (rows, cols) = self.dims();
+        let entries = self.triplets().into_iter().filter(|&(r, c, _)| r >= c);
+        SparseMatrix::from_row_major(rows, cols, entries)
+    }
+
+    // 上三角部分 (含对角线)
+    pub fn upper(&self) -> SparseMatrix<T> {
+        let (rows, cols) = self.dims();
+        let entries = self.triplets().into_iter().filter(|&(r, c, _)| r <= c);
+        SparseMatrix::from_row_major(rows, cols, entries)
+    }
+
+    // A^T = A: 行主序与 "列主序交换行列号" 逐项相同
+    pub fn is_symmetric(&self) -> bool {
+        if self.symmetric {
+            return true;
+        }
+        let (rows, cols) = self.dims();
+        rows == cols
+            && self.triplets().into_iter()
+                .eq(self.col_major_triplets().into_iter().map(|(r, c, v)| (c, r, v)))
+    }
+
+    // A^T = -A, 因此对角线上不能有非零元
+    pub fn is_skew_symmetric(&self) -> bool {
+        let (rows, cols) = self.dims();
+        rows == cols
+            && self.triplets().into_iter()
+                .eq(self.col_major_triplets().into_iter().map(|(r, c, v)| (c, r, -v)))
+    }
+
+    pub fn is_symmetric_storage(&self) -> bool {
+        self.symmetric
+    }
+
+    // 转为对称存储: 链表中只保留下三角, get/迭代/乘法仍按完整矩阵计算
+    pub fn to_symmetric_storage(&self) -> SparseMatrix<T> {
+        if !self.is_symmetric() {
+            panic!("Symmetric storage requires a symmetric matrix");
+        }
+        let (n, _) = self.dims();
+        let entries = self.stored_triplets().into_iter().filter(|&(r, c, _)| r >= c);
+        SparseMatrix::from_lower_row_major(n, entries)
+    }
+
+    // 展开为普通存储
+    pub fn to_general_storage(&self) -> SparseMatrix<T> {
+        let (rows, cols) = self.dims();
+        SparseMatrix::from_row_major(rows, cols, self.triplets())
+    }
+
+    // 对称矩阵的三元组, 每对镜像位置只给一次 (上下三角均可)
+    pub fn from_symmetric_triplets(n: usize, triplets: Vec<(usize, usize, T)>) -> Self {
+        let lower = triplets.into_iter().map(|(r, c, v)| (r.max(c), r.min(c), v)).collect();
+        let mut matrix = SparseMatrix::from_triplets(n, n, lower);
+        matrix.symmetric = true;
+        matrix
+    }
+}
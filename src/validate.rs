@@ -0,0 +1,309 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::{Link, Scalar, SparseMatrix};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chain {
+    Right,
+    Down,
+}
+
+impl Chain {
+    fn next<T>(self, node: &Link<T>) -> Option<Link<T>> {
+        match self {
+            Chain::Right => node.borrow().right.clone(),
+            Chain::Down => node.borrow().down.clone(),
+        }
+    }
+
+    // right 链按 (row, col) 排序, down 链按 (col, row) 排序
+    fn key(self, row: usize, col: usize) -> (usize, usize) {
+        match self {
+            Chain::Right => (row, col),
+            Chain::Down => (col, row),
+        }
+    }
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Chain::Right => write!(f, "right"),
+            Chain::Down => write!(f, "down"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation {
+    MissingHeadLink { chain: Chain },
+    MissingLink { chain: Chain, row: usize, col: usize },
+    // 链表绕回了某个中间结点而不是头结点
+    Cycle { chain: Chain, row: usize, col: usize },
+    Unsorted { chain: Chain, prev: (usize, usize), next: (usize, usize) },
+    Duplicate { chain: Chain, row: usize, col: usize },
+    OutOfBounds { row: usize, col: usize },
+    OnlyInRight { row: usize, col: usize },
+    OnlyInDown { row: usize, col: usize },
+    // 对称存储的矩阵只应保存下三角
+    AboveDiagonal { row: usize, col: usize },
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use InvariantViolation::*;
+        match self {
+            MissingHeadLink { chain } => write!(f, "head node has no {} link", chain),
+            MissingLink { chain, row, col } => write!(f, "node ({}, {}) has no {} link", row, col, chain),
+            Cycle { chain, row, col } => {
+                write!(f, "{} chain revisits node ({}, {}) without returning to the head", chain, row, col)
+            }
+            Unsorted { chain, prev, next } => write!(
+                f,
+                "{} chain out of order: ({}, {}) follows ({}, {})",
+                chain, next.0, next.1, prev.0, prev.1
+            ),
+            Duplicate { chain, row, col } => write!(f, "{} chain holds coordinate ({}, {}) more than once", chain, row, col),
+            OutOfBounds { row, col } => write!(f, "node ({}, {}) lies outside the matrix", row, col),
+            OnlyInRight { row, col } => write!(f, "node ({}, {}) is reachable from the right chain only", row, col),
+            OnlyInDown { row, col } => write!(f, "node ({}, {}) is reachable from the down chain only", row, col),
+            AboveDiagonal { row, col } => write!(f, "node ({}, {}) lies above the diagonal of a symmetric-storage matrix", row, col),
+        }
+    }
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    // 沿一条循环链表走一圈, 返回途经的结点
+    fn walk_chain(&self, chain: Chain, violations: &mut Vec<InvariantViolation>) -> Vec<Link<T>> {
+        let mut nodes = Vec::new();
+        let mut p = match chain.next(&self.head) {
+            Some(p) => p,
+            None => {
+                violations.push(InvariantViolation::MissingHeadLink { chain });
+                return nodes;
+            }
+        };
+        let mut seen = HashSet::new();
+        let mut prev: Option<(usize, usize)> = None;
+
+        while !Rc::ptr_eq(&p, &self.head) {
+            let (row, col) = {
+                let node = p.borrow();
+                (node.row, node.col)
+            };
+            if !seen.insert(Rc::as_ptr(&p)) {
+                violations.push(InvariantViolation::Cycle { chain, row, col });
+                break;
+            }
+            if let Some(prev) = prev {
+                let (a, b) = (chain.key(prev.0, prev.1), chain.key(row, col));
+                if a == b {
+                    violations.push(InvariantViolation::Duplicate { chain, row, col });
+                } else if b < a {
+                    violations.push(InvariantViolation::Unsorted { chain, prev, next: (row, col) });
+                }
+            }
+            prev = Some((row, col));
+            nodes.push(p.clone());
+
+            match chain.next(&p) {
+                Some(next) => p = next,
+                None => {
+                    violations.push(InvariantViolation::MissingLink { chain, row, col });
+                    break;
+                }
+            }
+        }
+        nodes
+    }
+
+    // 检查 right/down 两条循环链表的全部不变式, 返回所有违例
+    pub fn validate(&self) -> Result<(), Vec<InvariantViolation>> {
+        let (rows, cols) = self.dims();
+        let mut violations = Vec::new();
+        let right = self.walk_chain(Chain::Right, &mut violations);
+        let down = self.walk_chain(Chain::Down, &mut violations);
+
+        let in_right: HashSet<_> = right.iter().map(Rc::as_ptr).collect();
+        let in_down: HashSet<_> = down.iter().map(Rc::as_ptr).collect();
+
+        for node in &right {
+            let n = node.borrow();
+            if n.row == 0 || n.row > rows || n.col == 0 || n.col > cols {
+                violations.push(InvariantViolation::OutOfBounds { row: n.row, col: n.col });
+            }
+            if !in_down.contains(&Rc::as_ptr(node)) {
+                violations.push(InvariantViolation::OnlyInRight { row: n.row, col: n.col });
+            }
+            if self.symmetric && n.row < n.col {
+                violations.push(InvariantViolation::AboveDiagonal { row: n.row, col: n.col });
+            }
+        }
+        for node in down.iter().filter(|node| !in_right.contains(&Rc::as_ptr(node))) {
+            let n = node.borrow();
+            if n.row == 0 || n.row > rows || n.col == 0 || n.col > cols {
+                violations.push(InvariantViolation::OutOfBounds { row: n.row, col: n.col });
+            }
+            violations.push(InvariantViolation::OnlyInDown { row: n.row, col: n.col });
+        }
+
+        if violations.is_empty() { Ok(()) } else { Err(violations) }
+    }
+
+    // 调试构建下在构造/运算之后自动检查
+    pub(crate) fn debug_validate(&self, op: &str) {
+        if cfg!(debug_assertions) && let Err(violations) = self.validate() {
+            let report: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+            panic!("{} produced a malformed matrix:\n  {}", op, report.join("\n  "));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2x3, right 链: (1,1) (1,3) (2,2); down 链: (1,1) (2,2) (1,3)
+    fn sample() -> SparseMatrix {
+        SparseMatrix::from_triplets(2, 3, vec![(1, 1, 1), (1, 3, 2), (2, 2, 3)])
+    }
+
+    // 按 right 链顺序取出结点
+    fn nodes(m: &SparseMatrix) -> Vec<Link> {
+        let mut out = Vec::new();
+        let mut p = m.head.borrow().right.clone().unwrap();
+        while !Rc::ptr_eq(&p, &m.head) {
+            out.push(p.clone());
+            let next = p.borrow().right.clone().unwrap();
+            p = next;
+        }
+        out
+    }
+
+    fn violations(m: &SparseMatrix) -> Vec<InvariantViolation> {
+        m.validate().expect_err("corrupted matrix passed validation")
+    }
+
+    #[test]
+    fn valid_matrices_pass() {
+        assert_eq!(sample().validate(), Ok(()));
+        assert_eq!(SparseMatrix::<i32>::new(0, 0).validate(), Ok(()));
+        assert_eq!(sample().transpose().validate(), Ok(()));
+    }
+
+    #[test]
+    fn unsorted_right_chain() {
+        // 交换 right 链上的前两个结点: head -> (1,3) -> (1,1) -> (2,2)
+        let m = sample();
+        let n = nodes(&m);
+        m.head.borrow_mut().right = Some(n[1].clone());
+        n[1].borrow_mut().right = Some(n[0].clone());
+        n[0].borrow_mut().right = Some(n[2].clone());
+        assert_eq!(
+            violations(&m),
+            vec![InvariantViolation::Unsorted { chain: Chain::Right, prev: (1, 3), next: (1, 1) }]
+        );
+    }
+
+    #[test]
+    fn duplicate_coordinate() {
+        // (2,2) 改成 (1,3): 两条链上都与已有结点坐标相同
+        let m = sample();
+        let n = nodes(&m);
+        n[2].borrow_mut().row = 1;
+        n[2].borrow_mut().col = 3;
+        let v = violations(&m);
+        assert!(v.contains(&InvariantViolation::Duplicate { chain: Chain::Right, row: 1, col: 3 }));
+        assert!(v.iter().all(|v| matches!(v, InvariantViolation::Duplicate { .. } | InvariantViolation::Unsorted { .. })));
+    }
+
+    #[test]
+    fn node_skipped_by_down_chain() {
+        // down 链跳过 (2,2): head -> (1,1) -> (1,3)
+        let m = sample();
+        let n = nodes(&m);
+        n[0].borrow_mut().down = Some(n[1].clone());
+        assert_eq!(violations(&m), vec![InvariantViolation::OnlyInRight { row: 2, col: 2 }]);
+    }
+
+    #[test]
+    fn node_skipped_by_right_chain() {
+        // right 链跳过 (1,3)
+        let m = sample();
+        let n = nodes(&m);
+        n[0].borrow_mut().right = Some(n[2].clone());
+        assert_eq!(violations(&m), vec![InvariantViolation::OnlyInDown { row: 1, col: 3 }]);
+    }
+
+    #[test]
+    fn broken_down_link() {
+        // (1,1) 的 down 断开, 之后的结点从 down 链上都走不到
+        let m = sample();
+        let n = nodes(&m);
+        n[0].borrow_mut().down = None;
+        assert_eq!(
+            violations(&m),
+            vec![
+                InvariantViolation::MissingLink { chain: Chain::Down, row: 1, col: 1 },
+                InvariantViolation::OnlyInRight { row: 1, col: 3 },
+                InvariantViolation::OnlyInRight { row: 2, col: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_head_link() {
+        let m = sample();
+        m.head.borrow_mut().down = None;
+        let v = violations(&m);
+        assert_eq!(v[0], InvariantViolation::MissingHeadLink { chain: Chain::Down });
+        assert_eq!(v.len(), 4);
+    }
+
+    #[test]
+    fn cycle_that_skips_the_head() {
+        // (2,2) 的 right 指回 (1,1), 永远回不到头结点
+        let m = sample();
+        let n = nodes(&m);
+        n[2].borrow_mut().right = Some(n[0].clone());
+        let v = violations(&m);
+        assert!(v.contains(&InvariantViolation::Cycle { chain: Chain::Right, row: 1, col: 1 }));
+    }
+
+    #[test]
+    fn node_out_of_bounds() {
+        // 最后一个结点移到第 3 行: 两条链仍然有序, 只越界
+        let m = sample();
+        let n = nodes(&m);
+        n[2].borrow_mut().row = 3;
+        assert_eq!(violations(&m), vec![InvariantViolation::OutOfBounds { row: 3, col: 2 }]);
+
+        let m = sample();
+        nodes(&m)[0].borrow_mut().row = 0;
+        assert!(violations(&m).contains(&InvariantViolation::OutOfBounds { row: 0, col: 1 }));
+    }
+
+    #[test]
+    fn upper_triangle_in_symmetric_storage() {
+        let mut m = sample().submatrix(1..=2, 1..=2);
+        m.set(2, 1, 4);
+        m.symmetric = true;
+        assert_eq!(m.validate(), Ok(()));
+        m.symmetric = false;
+        m.set(1, 2, 5);
+        m.symmetric = true;
+        assert_eq!(violations(&m), vec![InvariantViolation::AboveDiagonal { row: 1, col: 2 }]);
+    }
+
+    // debug_validate 只在调试构建下检查
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "test produced a malformed matrix")]
+    fn debug_validate_panics() {
+        let m = sample();
+        nodes(&m)[2].borrow_mut().row = 3;
+        m.debug_validate("test");
+    }
+}
@@ -0,0 +1,252 @@
+use std::rc::Rc;
+
+use crate::{Scalar, SparseMatrix};
+
+// 稀疏向量: 按下标升序存放的 (index, value), 下标从 1 开始
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseVector<T = i32> {
+    len: usize,
+    entries: Vec<(usize, T)>,
+}
+
+impl<T: Scalar> SparseVector<T> {
+    pub fn new(len: usize) -> Self {
+        SparseVector { len, entries: Vec::new() }
+    }
+
+    pub fn from_pairs(len: usize, mut pairs: Vec<(usize, T)>) -> Self {
+        if let Some(&(i, _)) = pairs.iter().find(|&&(i, _)| i == 0 || i > len) {
+            panic!("Index {} out of bounds (valid indices are 1..={})", i, len);
+        }
+        pairs.sort_by_key(|&(i, _)| i);
+        if let Some(w) = pairs.windows(2).find(|w| w[0].0 == w[1].0) {
+            panic!("Index {} given more than once", w[0].0);
+        }
+        SparseVector { len, entries: pairs }
+    }
+
+    pub fn from_dense(values: &[T]) -> Self {
+        let entries = values.iter().enumerate()
+            .filter(|&(_, v)| !v.is_zero())
+            .map(|(i, &v)| (i + 1, v))
+            .collect();
+        SparseVector { len: values.len(), entries }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn entries(&self) -> &[(usize, T)] {
+        &self.entries
+    }
+
+    pub fn get(&self, i: usize) -> T {
+        if i == 0 || i > self.len {
+            panic!("Index {} out of bounds (valid indices are 1..={})", i, self.len);
+        }
+        self.entries.binary_search_by_key(&i, |&(j, _)| j).map_or(T::zero(), |k| self.entries[k].1)
+    }
+
+    pub fn to_dense(&self) -> Vec<T> {
+        let mut dense = vec![T::zero(); self.len];
+        for &(i, v) in &self.entries {
+            dense[i - 1] = v;
+        }
+        dense
+    }
+
+    pub fn dot(&self, other: &SparseVector<T>) -> T {
+        if self.len != other.len {
+            panic!("Dimensions mismatch for dot product");
+        }
+        let (a, b) = (&self.entries, &other.entries);
+        let (mut i, mut j, mut sum) = (0, 0, T::zero());
+        while i < a.len() && j < b.len() {
+            if a[i].0 < b[j].0 {
+                i += 1;
+            } else if b[j].0 < a[i].0 {
+                j += 1;
+            } else {
+                sum += a[i].1 * b[j].1;
+                i += 1;
+                j += 1;
+            }
+        }
+        sum
+    }
+
+    pub fn dot_dense(&self, x: &[T]) -> T {
+        if self.len != x.len() {
+            panic!("Dimensions mismatch for dot product");
+        }
+        self.entries.iter().fold(T::zero(), |sum, &(i, v)| sum + v * x[i - 1])
+    }
+
+    // self = a * x + self, 相加后为零的位置不再存储
+    pub fn axpy(&mut self, a: T, x: &SparseVector<T>) {
+        if self.len != x.len {
+            panic!("Dimensions mismatch for axpy");
+        }
+        let (y, x) = (&self.entries, &x.entries);
+        let mut out = Vec::with_capacity(y.len() + x.len());
+        let (mut i, mut j) = (0, 0);
+        while i < y.len() || j < x.len() {
+            let key_y = y.get(i).map_or(usize::MAX, |e| e.0);
+            let key_x = x.get(j).map_or(usize::MAX, |e| e.0);
+            if key_y < key_x {
+                out.push(y[i]);
+                i += 1;
+            } else if key_x < key_y {
+                if !(a * x[j].1).is_zero() {
+                    out.push((key_x, a * x[j].1));
+                }
+                j += 1;
+            } else {
+                let val = y[i].1 + a * x[j].1;
+                if !val.is_zero() {
+                    out.push((key_y, val));
+                }
+                i += 1;
+                j += 1;
+            }
+        }
+        self.entries = out;
+    }
+
+    pub fn norm_1(&self) -> T {
+        self.entries.iter().fold(T::zero(), |sum, &(_, v)| sum + v.abs())
+    }
+
+    pub fn norm_inf(&self) -> T {
+        self.entries.iter().fold(T::zero(), |m, &(_, v)| if v.abs() > m { v.abs() } else { m })
+    }
+
+    pub fn norm_2(&self) -> f64 {
+        self.entries.iter().map(|&(_, v)| v.to_f64() * v.to_f64()).sum::<f64>().sqrt()
+    }
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    // 第 i 行 (沿 right 链, 从该行第一个结点走到行末)
+    pub fn row_vector(&self, i: usize) -> SparseVector<T> {
+        let (rows, cols) = self.dims();
+        if i == 0 || i > rows {
+            panic!("Row index {} out of bounds (valid indices are 1..={})", i, rows);
+        }
+        SparseVector { len: cols, entries: self.logical_line(i) }
+    }
+
+    // 第 j 列 (沿 down 链, 从该列第一个结点走到列末)
+    pub fn col_vector(&self, j: usize) -> SparseVector<T> {
+        let (rows, cols) = self.dims();
+        if j == 0 || j > cols {
+            panic!("Column index {} out of bounds (valid indices are 1..={})", j, cols);
+        }
+        if self.symmetric {
+            return SparseVector { len: rows, entries: self.logical_line(j) };
+        }
+        SparseVector { len: rows, entries: self.stored_col(j) }
+    }
+
+    // y = A x, 只访问 x 的非零元所在的列: 沿 down 链走一遍, 跳过 x 为零的列
+    pub fn mul_sparse_vec(&self, x: &SparseVector<T>) -> SparseVector<T> {
+        let (rows, cols) = self.dims();
+        if x.len != cols {
+            panic!("Dimensions mismatch for matrix-vector product");
+        }
+        if self.symmetric {
+            let mut y = SparseVector::new(rows);
+            for &(c, xc) in &x.entries {
+                y.axpy(xc, &self.col_vector(c));
+            }
+            return y;
+        }
+        let mut products: Vec<(usize, T)> = Vec::new();
+        let mut xs = x.entries.iter().peekable();
+        let Some(&&(first, _)) = xs.peek() else {
+            return SparseVector::new(rows);
+        };
+        let mut p = self.down_predecessor(1, first).borrow().down.clone().unwrap();
+        while !Rc::ptr_eq(&p, &self.head) {
+            let next = {
+                let node = p.borrow();
+                while xs.peek().is_some_and(|&&(c, _)| c < node.col) {
+                    xs.next();
+                }
+                match xs.peek() {
+                    None => break,
+                    Some(&&(c, xc)) if c == node.col => products.push((node.row, node.value * xc)),
+                    Some(_) => {}
+                }
+                node.down.clone().unwrap()
+            };
+            p = next;
+        }
+        // 按行合并同一行的乘积, 相加后为零的不存储
+        products.sort_by_key(|&(r, _)| r);
+        let mut entries: Vec<(usize, T)> = Vec::with_capacity(products.len());
+        for (r, v) in products {
+            match entries.last_mut() {
+                Some(last) if last.0 == r => last.1 += v,
+                _ => entries.push((r, v)),
+            }
+        }
+        entries.retain(|&(_, v)| !v.is_zero());
+        SparseVector { len: rows, entries }
+    }
+
+    // 实际存储的第 i 行, 按列号升序
+    fn stored_row(&self, i: usize) -> Vec<(usize, T)> {
+        let mut out = Vec::new();
+        let mut p = self.right_predecessor(i, 1).borrow().right.clone().unwrap();
+        while !Rc::ptr_eq(&p, &self.head) {
+            let next = {
+                let node = p.borrow();
+                if node.row != i {
+                    break;
+                }
+                out.push((node.col, node.value));
+                node.right.clone().unwrap()
+            };
+            p = next;
+        }
+        out
+    }
+
+    // 实际存储的第 j 列, 按行号升序
+    fn stored_col(&self, j: usize) -> Vec<(usize, T)> {
+        let mut out = Vec::new();
+        let mut p = self.down_predecessor(1, j).borrow().down.clone().unwrap();
+        while !Rc::ptr_eq(&p, &self.head) {
+            let next = {
+                let node = p.borrow();
+                if node.col != j {
+                    break;
+                }
+                out.push((node.row, node.value));
+                node.down.clone().unwrap()
+            };
+            p = next;
+        }
+        out
+    }
+
+    // 逻辑上的第 i 行; 对称存储时第 i 行的上三角部分是第 i 列对角线以下的镜像,
+    // 由于对称, 它同时也是逻辑上的第 i 列
+    fn logical_line(&self, i: usize) -> Vec<(usize, T)> {
+        let mut line = self.stored_row(i);
+        if self.symmetric {
+            line.extend(self.stored_col(i).into_iter().filter(|&(r, _)| r > i));
+        }
+        line
+    }
+}
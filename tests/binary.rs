@@ -0,0 +1,111 @@
+use sparse_matrix_proj::{BinaryError, SparseMatrix};
+
+fn sample() -> SparseMatrix {
+    SparseMatrix::from_triplets(3, 4, vec![(1, 1, 2), (1, 4, -7), (3, 2, i32::MAX), (3, 3, i32::MIN)])
+}
+
+fn encode(m: &SparseMatrix) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    m.write_binary(&mut bytes).unwrap();
+    bytes
+}
+
+// 与格式中使用的 CRC-32 (IEEE) 相同, 用来构造校验和正确但内容异常的文件
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in bytes {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn reseal(bytes: &mut [u8]) {
+    let n = bytes.len() - 4;
+    let crc = crc32(&bytes[..n]);
+    bytes[n..].copy_from_slice(&crc.to_le_bytes());
+}
+
+#[test]
+fn round_trip() {
+    for m in [sample(), SparseMatrix::new(0, 0), SparseMatrix::new(5, 2)] {
+        let back = SparseMatrix::<i32>::read_binary(encode(&m).as_slice()).unwrap();
+        assert_eq!(back, m);
+        assert!(back.validate().is_ok());
+    }
+}
+
+#[test]
+fn round_trip_other_value_types() {
+    let m = SparseMatrix::from_triplets(2, 3, vec![(1, 2, 0.25), (2, 1, -1e300), (2, 3, f64::MIN_POSITIVE)]);
+    let mut bytes = Vec::new();
+    m.write_binary(&mut bytes).unwrap();
+    assert_eq!(SparseMatrix::<f64>::read_binary(bytes.as_slice()).unwrap(), m);
+    // 元素类型不符时拒绝, 不做隐式转换
+    assert!(matches!(SparseMatrix::<i32>::read_binary(bytes.as_slice()), Err(BinaryError::UnsupportedValueType(4))));
+
+    let m = SparseMatrix::from_triplets(1, 2, vec![(1, 2, i64::MIN)]);
+    let mut bytes = Vec::new();
+    m.write_binary(&mut bytes).unwrap();
+    assert_eq!(SparseMatrix::<i64>::read_binary(bytes.as_slice()).unwrap(), m);
+}
+
+#[test]
+fn truncated_input_is_rejected() {
+    let bytes = encode(&sample());
+    for len in [0, 3, 20, bytes.len() - 1] {
+        assert!(
+            matches!(SparseMatrix::<i32>::read_binary(&bytes[..len]), Err(BinaryError::Truncated { .. })),
+            "length {}", len
+        );
+    }
+    let mut longer = bytes.clone();
+    longer.push(0);
+    assert!(matches!(SparseMatrix::<i32>::read_binary(longer.as_slice()), Err(BinaryError::TrailingData { .. })));
+}
+
+#[test]
+fn bad_checksum_and_header_are_rejected() {
+    let bytes = encode(&sample());
+    let mut flipped = bytes.clone();
+    flipped[40] ^= 1;
+    assert!(matches!(SparseMatrix::<i32>::read_binary(flipped.as_slice()), Err(BinaryError::ChecksumMismatch { .. })));
+
+    let mut magic = bytes.clone();
+    magic[0] = b'X';
+    assert!(matches!(SparseMatrix::<i32>::read_binary(magic.as_slice()), Err(BinaryError::BadMagic)));
+
+    let mut version = bytes;
+    version[4] = 9;
+    assert!(matches!(SparseMatrix::<i32>::read_binary(version.as_slice()), Err(BinaryError::UnsupportedVersion(9))));
+}
+
+#[test]
+fn corrupt_structure_with_valid_checksum_is_rejected() {
+    // 第 1 行的两个列号交换, 不再严格递增
+    let mut unsorted = encode(&sample());
+    let col_at = 32 + 8 * 4;
+    let (first, second) = (unsorted[col_at..col_at + 8].to_vec(), unsorted[col_at + 8..col_at + 16].to_vec());
+    unsorted[col_at..col_at + 8].copy_from_slice(&second);
+    unsorted[col_at + 8..col_at + 16].copy_from_slice(&first);
+    reseal(&mut unsorted);
+    assert!(matches!(SparseMatrix::<i32>::read_binary(unsorted.as_slice()), Err(BinaryError::Corrupt(_))));
+}
+
+#[test]
+fn huge_column_count_does_not_allocate() {
+    let mut bytes = encode(&SparseMatrix::from_triplets(1, 1, vec![(1, 1, 5)]));
+    bytes[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+    reseal(&mut bytes);
+    assert!(matches!(SparseMatrix::<i32>::read_binary(bytes.as_slice()), Err(BinaryError::Corrupt(_))));
+
+    // 能寻址但远超元素个数的列数照常读入, 不按列数分配内存
+    let wide = 1u64 << 40;
+    bytes[16..24].copy_from_slice(&wide.to_le_bytes());
+    reseal(&mut bytes);
+    let m = SparseMatrix::<i32>::read_binary(bytes.as_slice()).unwrap();
+    assert_eq!(m.dims(), (1, wide as usize));
+    assert_eq!(m.get(1, 1), 5);
+}
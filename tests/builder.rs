@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+
+use proptest::prelude::*;
+use sparse_matrix_proj::{DuplicatePolicy, SparseMatrix, SparseMatrixBuilder, TripletError};
+
+type Entries = Vec<(usize, usize, i32)>;
+
+fn build(policy: DuplicatePolicy, pushes: &[(usize, usize, i32)]) -> Result<Entries, TripletError> {
+    let mut builder = SparseMatrixBuilder::new(3, 3).duplicate_policy(policy);
+    for &(r, c, v) in pushes {
+        builder.push(r, c, v).unwrap();
+    }
+    let m: SparseMatrix = builder.build()?;
+    assert!(m.validate().is_ok());
+    Ok(m.iter().collect())
+}
+
+// 每个策略对应一个期望结果, 有序输入 (走链表) 和乱序输入 (走缓冲) 都要得到它
+fn check(sorted: &[(usize, usize, i32)], unsorted: &[(usize, usize, i32)], expected: [Result<Entries, TripletError>; 4]) {
+    let policies = [DuplicatePolicy::Sum, DuplicatePolicy::KeepFirst, DuplicatePolicy::KeepLast, DuplicatePolicy::Reject];
+    for (policy, expected) in policies.into_iter().zip(expected) {
+        assert_eq!(build(policy, sorted), expected, "{:?} on sorted input", policy);
+        assert_eq!(build(policy, unsorted), expected, "{:?} on unsorted input", policy);
+    }
+}
+
+#[test]
+fn duplicate_policies() {
+    check(
+        &[(1, 1, 2), (1, 1, 3), (1, 3, 1), (2, 2, 4)],
+        &[(2, 2, 4), (1, 1, 2), (1, 3, 1), (1, 1, 3)],
+        [
+            Ok(vec![(1, 1, 5), (1, 3, 1), (2, 2, 4)]),
+            Ok(vec![(1, 1, 2), (1, 3, 1), (2, 2, 4)]),
+            Ok(vec![(1, 1, 3), (1, 3, 1), (2, 2, 4)]),
+            Err(TripletError::Duplicate { row: 1, col: 1 }),
+        ],
+    );
+}
+
+// 值为 0 的元素要参与合并, 不能在转入缓冲之前就被丢掉
+#[test]
+fn zero_values_take_part_in_duplicate_handling() {
+    check(
+        &[(1, 1, 0), (1, 1, 3), (1, 2, 5)],
+        &[(1, 1, 0), (1, 2, 5), (1, 1, 3)],
+        [
+            Ok(vec![(1, 1, 3), (1, 2, 5)]),
+            Ok(vec![(1, 2, 5)]),
+            Ok(vec![(1, 1, 3), (1, 2, 5)]),
+            Err(TripletError::Duplicate { row: 1, col: 1 }),
+        ],
+    );
+    check(
+        &[(1, 1, 3), (1, 1, 0), (1, 2, 5)],
+        &[(1, 1, 3), (1, 2, 5), (1, 1, 0)],
+        [
+            Ok(vec![(1, 1, 3), (1, 2, 5)]),
+            Ok(vec![(1, 1, 3), (1, 2, 5)]),
+            Ok(vec![(1, 2, 5)]),
+            Err(TripletError::Duplicate { row: 1, col: 1 }),
+        ],
+    );
+}
+
+#[test]
+fn cancelled_and_zero_entries_are_not_stored() {
+    check(
+        &[(1, 1, 2), (1, 1, -2), (2, 1, 0), (3, 3, 7)],
+        &[(3, 3, 7), (1, 1, 2), (2, 1, 0), (1, 1, -2)],
+        [
+            Ok(vec![(3, 3, 7)]),
+            Ok(vec![(1, 1, 2), (3, 3, 7)]),
+            Ok(vec![(1, 1, -2), (3, 3, 7)]),
+            Err(TripletError::Duplicate { row: 1, col: 1 }),
+        ],
+    );
+    // 全部为零时得到空矩阵, 链表仍然合法
+    assert_eq!(build(DuplicatePolicy::Sum, &[(1, 1, 0), (2, 3, 0)]), Ok(vec![]));
+}
+
+#[test]
+fn out_of_bounds_push_is_rejected() {
+    let mut builder: SparseMatrixBuilder = SparseMatrixBuilder::new(2, 2);
+    assert_eq!(builder.push(3, 1, 1), Err(TripletError::OutOfBounds { row: 3, col: 1, rows: 2, cols: 2 }));
+    assert_eq!(builder.push(1, 0, 1), Err(TripletError::OutOfBounds { row: 1, col: 0, rows: 2, cols: 2 }));
+    assert!(builder.is_empty());
+}
+
+// 逆序元素与已串进链表的同一坐标合并, 先 push 的一方算 "first"
+#[test]
+fn out_of_order_pushes_merge_with_the_list() {
+    check(
+        &[(1, 2, 1), (1, 2, 10), (2, 1, 3), (3, 3, 4), (3, 3, 40)],
+        &[(1, 2, 1), (3, 3, 4), (2, 1, 3), (1, 2, 10), (3, 3, 40)],
+        [
+            Ok(vec![(1, 2, 11), (2, 1, 3), (3, 3, 44)]),
+            Ok(vec![(1, 2, 1), (2, 1, 3), (3, 3, 4)]),
+            Ok(vec![(1, 2, 10), (2, 1, 3), (3, 3, 40)]),
+            Err(TripletError::Duplicate { row: 1, col: 2 }),
+        ],
+    );
+    // 完全逆序: 链表里只有第一个元素
+    check(
+        &[(1, 1, 1), (1, 3, 2), (2, 2, 3), (3, 1, 4)],
+        &[(3, 1, 4), (2, 2, 3), (1, 3, 2), (1, 1, 1)],
+        [
+            Ok(vec![(1, 1, 1), (1, 3, 2), (2, 2, 3), (3, 1, 4)]),
+            Ok(vec![(1, 1, 1), (1, 3, 2), (2, 2, 3), (3, 1, 4)]),
+            Ok(vec![(1, 1, 1), (1, 3, 2), (2, 2, 3), (3, 1, 4)]),
+            Ok(vec![(1, 1, 1), (1, 3, 2), (2, 2, 3), (3, 1, 4)]),
+        ],
+    );
+}
+
+// 维数不决定内存: 构造器和结果都只按元素个数分配
+#[test]
+fn wide_dims() {
+    let (rows, cols) = (1 << 20, 1usize << 34);
+    let mut builder: SparseMatrixBuilder = SparseMatrixBuilder::new(rows, cols);
+    for (r, c, v) in [(5, cols, 1), (1, 1, 2), (rows, 7, 3), (5, cols, 4), (5, 1 << 33, 5)] {
+        builder.push(r, c, v).unwrap();
+    }
+    let m = builder.build().unwrap();
+    assert!(m.validate().is_ok());
+    assert_eq!(m.dims(), (rows, cols));
+    assert_eq!(m.iter().collect::<Vec<_>>(), vec![(1, 1, 2), (5, 1 << 33, 5), (5, cols, 5), (rows, 7, 3)]);
+    assert_eq!(m.get(5, cols), 5);
+}
+
+fn pushes() -> impl Strategy<Value = Vec<(usize, usize, i32)>> {
+    prop::collection::vec((1..=6usize, 1..=5usize, -3..=3i32), 0..40)
+}
+
+proptest! {
+    // 任意顺序的 push 与按坐标逐个合并的参考实现一致
+    #[test]
+    fn any_push_order_matches_reference(input in pushes(), sort_prefix in 0..40usize) {
+        let mut input = input;
+        let prefix = sort_prefix.min(input.len());
+        input[..prefix].sort_by_key(|&(r, c, _)| (r, c));
+        for policy in [DuplicatePolicy::Sum, DuplicatePolicy::KeepFirst, DuplicatePolicy::KeepLast] {
+            let mut reference: BTreeMap<(usize, usize), i32> = BTreeMap::new();
+            for &(r, c, v) in &input {
+                reference
+                    .entry((r, c))
+                    .and_modify(|old| match policy {
+                        DuplicatePolicy::Sum => *old += v,
+                        DuplicatePolicy::KeepLast => *old = v,
+                        _ => {}
+                    })
+                    .or_insert(v);
+            }
+            let expected: Entries = reference.into_iter().filter(|&(_, v)| v != 0).map(|((r, c), v)| (r, c, v)).collect();
+            let mut builder = SparseMatrixBuilder::new(6, 5).duplicate_policy(policy);
+            for &(r, c, v) in &input {
+                builder.push(r, c, v).unwrap();
+            }
+            prop_assert_eq!(builder.len(), input.len());
+            let m: SparseMatrix = builder.build().unwrap();
+            prop_assert!(m.validate().is_ok());
+            prop_assert_eq!(m.iter().collect::<Entries>(), expected);
+        }
+
+        let mut sorted: Vec<(usize, usize)> = input.iter().map(|&(r, c, _)| (r, c)).collect();
+        sorted.sort();
+        let first_duplicate = sorted.windows(2).find(|w| w[0] == w[1]).map(|w| w[0]);
+        let mut builder: SparseMatrixBuilder = SparseMatrixBuilder::new(6, 5).duplicate_policy(DuplicatePolicy::Reject);
+        for &(r, c, v) in &input {
+            builder.push(r, c, v).unwrap();
+        }
+        let error = builder.build().err();
+        prop_assert_eq!(error, first_duplicate.map(|(row, col)| TripletError::Duplicate { row, col }));
+    }
+}
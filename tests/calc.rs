@@ -0,0 +1,44 @@
+use sparse_matrix_proj::{CalcError, Session, SparseMatrix};
+
+fn session() -> Session {
+    let mut s = Session::new();
+    s.insert("A", SparseMatrix::from_triplets(1, 1, vec![(1, 1, i32::MAX)]));
+    s.insert("M", SparseMatrix::from_triplets(1, 1, vec![(1, 1, i32::MIN)]));
+    s
+}
+
+#[test]
+fn overflow_is_reported_as_an_error() {
+    let mut s = session();
+    for stmt in ["C = A * A'", "C = A + A", "C = -M", "C = M - A", "C = (A * A)'"] {
+        assert!(matches!(s.execute(stmt), Err(CalcError::Overflow)), "{}", stmt);
+    }
+    // 失败的语句不改动变量表
+    assert!(s.get("C").is_none());
+    assert_eq!(s.execute("C = A - A").unwrap().as_deref(), Some("C"));
+    assert_eq!(s.get("C").unwrap().iter().len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "overflow")]
+fn eval_panics_on_overflow() {
+    let a = SparseMatrix::from_triplets(1, 1, vec![(1, 1, i32::MAX)]);
+    (&a * &a).eval();
+}
+
+// load/save 的变量名与赋值语句使用同一条标识符规则
+#[test]
+fn load_and_save_validate_the_name() {
+    let mut s = session();
+    let path = std::env::temp_dir().join(format!("sparse-calc-name-test-{}.mtx", std::process::id()));
+    let path = path.to_str().unwrap();
+    for stmt in [format!("load 1x {}", path), format!("save A-B {}", path), format!("load A' {}", path)] {
+        assert!(matches!(s.execute(&stmt), Err(CalcError::Syntax(msg)) if msg.starts_with("invalid variable name")), "{}", stmt);
+    }
+    assert!(matches!(s.load("a.b", path, None), Err(CalcError::Syntax(_))));
+
+    s.execute(&format!("save A {}", path)).unwrap();
+    assert_eq!(s.execute(&format!("load _copy2 {}", path)).unwrap().as_deref(), Some("_copy2"));
+    assert_eq!(s.get("_copy2"), s.get("A"));
+    std::fs::remove_file(path).unwrap();
+}
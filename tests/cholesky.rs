@@ -0,0 +1,48 @@
+use sparse_matrix_proj::{CholeskyError, SparseMatrix};
+
+fn assert_close(a: &[f64], b: &[f64]) {
+    assert_eq!(a.len(), b.len());
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() < 1e-10, "component {}: {} vs {}", i + 1, x, y);
+    }
+}
+
+// L·L^T 逐元素还原 A, L 为下三角且对角元为正
+#[test]
+fn factor_reproduces_the_matrix() {
+    let a: SparseMatrix<f64> = SparseMatrix::laplacian_2d(4, 3);
+    let chol = a.cholesky().unwrap();
+    let l = chol.factor();
+    assert!(l.iter().all(|(r, c, _)| r >= c));
+    assert!((1..=12).all(|i| l.get(i, i) > 0.0));
+    assert_eq!(l.iter().len(), chol.symbolic().nnz());
+
+    let llt = l.multiply(&l.transpose());
+    assert_close(llt.to_dense().as_slice(), a.to_dense().as_slice());
+}
+
+// 2x2 的已知分解: [[4, 2], [2, 5]] = L·L^T, L = [[2, 0], [1, 2]]
+#[test]
+fn small_known_factor_and_solve() {
+    let a = SparseMatrix::from_triplets(2, 2, vec![(1, 1, 4.0), (1, 2, 2.0), (2, 1, 2.0), (2, 2, 5.0)]);
+    let chol = a.cholesky().unwrap();
+    assert_close(chol.factor().to_dense().as_slice(), &[2.0, 0.0, 1.0, 2.0]);
+    // A x = b, x = (1, -1)
+    assert_close(&chol.solve(&[2.0, -3.0]), &[1.0, -1.0]);
+}
+
+#[test]
+fn invalid_input_is_rejected() {
+    // 对称但不定: 特征值为 3 和 -1
+    let indefinite = SparseMatrix::from_triplets(2, 2, vec![(1, 1, 1.0), (1, 2, 2.0), (2, 1, 2.0), (2, 2, 1.0)]);
+    assert!(matches!(indefinite.cholesky(), Err(CholeskyError::NotPositiveDefinite { col: 2, .. })));
+
+    let singular = SparseMatrix::from_triplets(2, 2, vec![(1, 1, 1.0)]);
+    assert!(matches!(singular.cholesky(), Err(CholeskyError::NotPositiveDefinite { col: 2, .. })));
+
+    let unsymmetric = SparseMatrix::from_triplets(2, 2, vec![(1, 1, 4.0), (1, 2, 1.0), (2, 2, 4.0)]);
+    assert!(matches!(unsymmetric.cholesky(), Err(CholeskyError::NotSymmetric { .. })));
+
+    let rectangular: SparseMatrix<f64> = SparseMatrix::new(2, 3);
+    assert_eq!(rectangular.cholesky().err(), Some(CholeskyError::NotSquare { rows: 2, cols: 3 }));
+}
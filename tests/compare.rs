@@ -0,0 +1,89 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use sparse_matrix_proj::{MatrixMismatch, SparseMatrix};
+
+fn hash_of(m: &SparseMatrix) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    m.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn pair(left: Vec<(usize, usize, f64)>, right: Vec<(usize, usize, f64)>) -> (SparseMatrix<f64>, SparseMatrix<f64>) {
+    (SparseMatrix::from_triplets(2, 3, left), SparseMatrix::from_triplets(2, 3, right))
+}
+
+#[test]
+fn absolute_tolerance() {
+    let (a, b) = pair(vec![(1, 1, 1.0), (2, 3, 0.5)], vec![(1, 1, 1.05), (2, 3, 0.5)]);
+    assert_eq!(a.approx_eq(&b, 0.1, 0.0), Ok(()));
+    assert_eq!(
+        a.approx_eq(&b, 0.01, 0.0),
+        Err(MatrixMismatch::Value { row: 1, col: 1, left: 1.0, right: 1.05 })
+    );
+    // 缺失的元素按 0 计
+    let (a, b) = pair(vec![(1, 2, 0.05)], vec![]);
+    assert_eq!(a.approx_eq(&b, 0.1, 0.0), Ok(()));
+    assert_eq!(b.approx_eq(&a, 0.01, 0.0), Err(MatrixMismatch::Value { row: 1, col: 2, left: 0.0, right: 0.05 }));
+}
+
+#[test]
+fn relative_tolerance() {
+    // 相对误差按两者中较大的模计算: |1000 - 1010| <= 0.01 * 1010
+    let (a, b) = pair(vec![(2, 2, 1000.0)], vec![(2, 2, 1010.0)]);
+    assert_eq!(a.approx_eq(&b, 0.0, 0.01), Ok(()));
+    assert!(a.approx_eq(&b, 0.0, 0.005).is_err());
+    // 相对容差对零附近的值不起作用, 需要绝对容差
+    let (a, b) = pair(vec![(1, 3, 1e-12)], vec![]);
+    assert!(a.approx_eq(&b, 0.0, 0.5).is_err());
+    assert_eq!(a.approx_eq(&b, 1e-9, 0.5), Ok(()));
+    // 两者相加
+    let (a, b) = pair(vec![(1, 1, 100.0)], vec![(1, 1, 101.5)]);
+    assert!(a.approx_eq(&b, 1.0, 0.0).is_err());
+    assert!(a.approx_eq(&b, 0.0, 0.01).is_err());
+    assert_eq!(a.approx_eq(&b, 1.0, 0.01), Ok(()));
+}
+
+#[test]
+fn reports_the_first_mismatch_in_row_major_order() {
+    let (a, b) = pair(
+        vec![(1, 1, 1.0), (1, 3, 2.0), (2, 1, 3.0), (2, 2, 4.0)],
+        vec![(1, 1, 1.0), (1, 2, 9.0), (2, 1, 7.0), (2, 2, 4.0)],
+    );
+    assert_eq!(a.approx_eq(&b, 1e-9, 0.0), Err(MatrixMismatch::Value { row: 1, col: 2, left: 0.0, right: 9.0 }));
+    assert_eq!(b.approx_eq(&a, 1e-9, 0.0), Err(MatrixMismatch::Value { row: 1, col: 2, left: 9.0, right: 0.0 }));
+
+    let (a, b) = pair(vec![(1, 3, 2.0), (2, 1, 3.0)], vec![(2, 1, 3.5)]);
+    let err = a.approx_eq(&b, 1e-9, 0.0).unwrap_err();
+    assert_eq!(err, MatrixMismatch::Value { row: 1, col: 3, left: 2.0, right: 0.0 });
+    assert_eq!(err.to_string(), "entries at (1, 3) differ: 2 vs 0");
+}
+
+#[test]
+fn dims_mismatch() {
+    let a = SparseMatrix::<i32>::new(2, 3);
+    let b = SparseMatrix::<i32>::new(3, 2);
+    let err = a.approx_eq(&b, 1.0, 1.0).unwrap_err();
+    assert_eq!(err, MatrixMismatch::Dims { left: (2, 3), right: (3, 2) });
+    assert_eq!(err.to_string(), "dimensions differ: 2x3 vs 3x2");
+    assert_ne!(a, b);
+}
+
+#[test]
+fn equality_and_hash_ignore_the_storage_mode() {
+    let general: SparseMatrix = SparseMatrix::from_triplets(3, 3, vec![(1, 1, 4), (1, 2, -1), (2, 1, -1), (3, 3, 2)]);
+    let stored = general.to_symmetric_storage();
+    let from_symmetric = SparseMatrix::from_symmetric_triplets(3, vec![(1, 2, -1), (3, 3, 2), (1, 1, 4)]);
+    assert!(stored.is_symmetric_storage());
+    assert_eq!(general, stored);
+    assert_eq!(general, from_symmetric);
+    assert_eq!(hash_of(&general), hash_of(&stored));
+    assert_eq!(hash_of(&general), hash_of(&from_symmetric));
+    assert_eq!(hash_of(&stored.to_general_storage()), hash_of(&general));
+    assert_eq!(general.approx_eq(&stored, 0.0, 0.0), Ok(()));
+
+    // 维数不同的空矩阵不相等
+    let empty = SparseMatrix::<i32>::new(2, 2);
+    assert_ne!(empty, SparseMatrix::new(2, 3));
+    assert_eq!(empty.clone(), empty);
+}
@@ -0,0 +1,545 @@
+// 差分测试: 随机生成三元组, 把 SparseMatrix 的每个运算结果与朴素稠密实现逐元素对比
+use proptest::prelude::*;
+use sparse_matrix_proj::{SparseMatrix, SparseVector, TripletError};
+
+type Dense = Vec<Vec<i32>>;
+
+fn zeros(rows: usize, cols: usize) -> Dense {
+    vec![vec![0; cols]; rows]
+}
+
+fn dense_add(a: &Dense, b: &Dense) -> Dense {
+    a.iter().zip(b).map(|(x, y)| x.iter().zip(y).map(|(p, q)| p + q).collect()).collect()
+}
+
+fn dense_multiply(a: &Dense, b: &Dense, rows: usize, inner: usize, cols: usize) -> Dense {
+    let mut c = zeros(rows, cols);
+    for i in 0..rows {
+        for j in 0..cols {
+            c[i][j] = (0..inner).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    c
+}
+
+// 结果 (i, j) = a(row_idx[i], col_idx[j]), 下标从 1 开始
+fn dense_select(a: &Dense, row_idx: &[usize], col_idx: &[usize]) -> Dense {
+    row_idx.iter().map(|&i| col_idx.iter().map(|&j| a[i - 1][j - 1]).collect()).collect()
+}
+
+// 最大值及其 1 起始下标, 并列时取最小下标; 空序列为 None
+fn dense_max(line: impl Iterator<Item = i32>) -> Option<(usize, i32)> {
+    line.enumerate().fold(None, |best, (k, v)| match best {
+        Some((_, b)) if b >= v => best,
+        _ => Some((k + 1, v)),
+    })
+}
+
+fn dense_reductions(m: &SparseMatrix, dense: &Dense, rows: usize, cols: usize) {
+    let col = |j: usize| dense.iter().map(move |row| row[j]);
+    assert_eq!(m.row_sums(), dense.iter().map(|row| row.iter().sum()).collect::<Vec<i32>>());
+    assert_eq!(m.col_sums(), (0..cols).map(|j| col(j).sum()).collect::<Vec<i32>>());
+    assert_eq!(m.row_max(), dense.iter().map(|row| dense_max(row.iter().copied())).collect::<Vec<_>>());
+    assert_eq!(m.col_max(), (0..cols).map(|j| dense_max(col(j))).collect::<Vec<_>>());
+    let diagonal: Vec<i32> = (0..rows.min(cols)).map(|i| dense[i][i]).collect();
+    assert_eq!(m.trace(), diagonal.iter().sum::<i32>());
+    assert_eq!(m.diagonal(), diagonal);
+    assert_eq!(m.norm_1(), (0..cols).map(|j| col(j).map(i32::abs).sum()).max().unwrap_or(0));
+    assert_eq!(m.norm_inf(), dense.iter().map(|row| row.iter().map(|v| v.abs()).sum()).max().unwrap_or(0));
+    let squares: i32 = dense.iter().flatten().map(|v| v * v).sum();
+    assert!((m.norm_frobenius() - (squares as f64).sqrt()).abs() < 1e-12);
+}
+
+fn permutation(n: usize) -> impl Strategy<Value = Vec<usize>> {
+    Just((1..=n).collect::<Vec<_>>()).prop_shuffle()
+}
+
+fn dense_transpose(a: &Dense, rows: usize, cols: usize) -> Dense {
+    (0..cols).map(|j| (0..rows).map(|i| a[i][j]).collect()).collect()
+}
+
+// 维数、链表不变量、get 的每个位置以及行主序的非零元都要与稠密矩阵一致; 不允许存储显式的 0
+fn assert_matches(m: &SparseMatrix, dense: &Dense, rows: usize, cols: usize) {
+    assert_eq!(m.dims(), (rows, cols));
+    if let Err(violations) = m.validate() {
+        panic!("invariants violated: {:?}", violations);
+    }
+    for i in 1..=rows {
+        for j in 1..=cols {
+            assert_eq!(m.get(i, j), dense[i - 1][j - 1], "entry ({}, {})", i, j);
+        }
+    }
+    let expected: Vec<(usize, usize, i32)> = (1..=rows)
+        .flat_map(|i| (1..=cols).map(move |j| (i, j)))
+        .filter(|&(i, j)| dense[i - 1][j - 1] != 0)
+        .map(|(i, j)| (i, j, dense[i - 1][j - 1]))
+        .collect();
+    assert_eq!(m.iter().collect::<Vec<_>>(), expected);
+}
+
+// 值域取得很小, 让加法中的相消 (结果为 0) 经常出现
+fn entry() -> impl Strategy<Value = i32> {
+    prop_oneof![3 => Just(0), 1 => -3..=3i32]
+}
+
+// 给定维数的稠密矩阵, 以及它的非零元按随机顺序排列的三元组
+fn matrix(rows: usize, cols: usize) -> impl Strategy<Value = (Dense, Vec<(usize, usize, i32)>)> {
+    prop::collection::vec(prop::collection::vec(entry(), cols), rows).prop_flat_map(move |dense| {
+        let triplets: Vec<(usize, usize, i32)> = (1..=rows)
+            .flat_map(|i| (1..=cols).map(move |j| (i, j)))
+            .filter(|&(i, j)| dense[i - 1][j - 1] != 0)
+            .map(|(i, j)| (i, j, dense[i - 1][j - 1]))
+            .collect();
+        (Just(dense), Just(triplets).prop_shuffle())
+    })
+}
+
+// 维数从 0 开始, 覆盖空矩阵、单行、单列和 1x1
+fn dim() -> impl Strategy<Value = usize> {
+    prop_oneof![1 => Just(0), 2 => Just(1), 4 => 2..=7usize]
+}
+
+fn build((dense, triplets): (Dense, Vec<(usize, usize, i32)>), rows: usize, cols: usize) -> (SparseMatrix, Dense) {
+    (SparseMatrix::from_triplets(rows, cols, triplets), dense)
+}
+
+proptest! {
+    #[test]
+    fn from_triplets_matches_dense(
+        (rows, cols, input) in (dim(), dim()).prop_flat_map(|(r, c)| (Just(r), Just(c), matrix(r, c)))
+    ) {
+        let (m, dense) = build(input, rows, cols);
+        assert_matches(&m, &dense, rows, cols);
+    }
+
+    #[test]
+    fn add_matches_dense(
+        (rows, cols, a, b) in (dim(), dim()).prop_flat_map(|(r, c)| (Just(r), Just(c), matrix(r, c), matrix(r, c)))
+    ) {
+        let (a, da) = build(a, rows, cols);
+        let (b, db) = build(b, rows, cols);
+        assert_matches(&a.add(&b), &dense_add(&da, &db), rows, cols);
+        assert_matches(&b.add(&a), &dense_add(&da, &db), rows, cols);
+    }
+
+    #[test]
+    fn multiply_matches_dense(
+        (rows, inner, cols, a, b) in (dim(), dim(), dim()).prop_flat_map(|(r, k, c)| {
+            (Just(r), Just(k), Just(c), matrix(r, k), matrix(k, c))
+        })
+    ) {
+        let (a, da) = build(a, rows, inner);
+        let (b, db) = build(b, inner, cols);
+        assert_matches(&a.multiply(&b), &dense_multiply(&da, &db, rows, inner, cols), rows, cols);
+    }
+
+    #[test]
+    fn transpose_matches_dense(
+        (rows, cols, input) in (dim(), dim()).prop_flat_map(|(r, c)| (Just(r), Just(c), matrix(r, c)))
+    ) {
+        let (m, dense) = build(input, rows, cols);
+        let t = m.transpose();
+        assert_matches(&t, &dense_transpose(&dense, rows, cols), cols, rows);
+        assert_matches(&t.transpose(), &dense, rows, cols);
+    }
+
+    #[test]
+    fn mul_vec_matches_dense(
+        (rows, cols, input, x) in (dim(), dim()).prop_flat_map(|(r, c)| {
+            (Just(r), Just(c), matrix(r, c), prop::collection::vec(-3..=3i32, c))
+        })
+    ) {
+        let (m, dense) = build(input, rows, cols);
+        let expected: Vec<i32> = dense.iter().map(|row| row.iter().zip(&x).map(|(a, b)| a * b).sum()).collect();
+        prop_assert_eq!(m.mul_vec(&x), expected);
+    }
+
+    // 并行版本与串行版本逐元素相同; 线程数取 1, 2 以及多于行数 (有的线程分不到行), 维数可以为 0
+    #[test]
+    fn parallel_matches_serial(
+        (rows, inner, cols, a, b, c, x) in (dim(), dim(), dim()).prop_flat_map(|(r, k, c)| {
+            (
+                Just(r),
+                Just(k),
+                Just(c),
+                matrix(r, k),
+                matrix(r, k),
+                matrix(k, c),
+                prop::collection::vec(-3..=3i32, k),
+            )
+        })
+    ) {
+        let (a, _) = build(a, rows, inner);
+        let (b, _) = build(b, rows, inner);
+        let (c, _) = build(c, inner, cols);
+        for threads in [1, 2, rows + 3] {
+            let sum = a.par_add(&b, threads);
+            prop_assert_eq!(&sum, &a.add(&b));
+            prop_assert!(sum.validate().is_ok());
+            let product = a.par_multiply(&c, threads);
+            prop_assert_eq!(&product, &a.multiply(&c));
+            prop_assert!(product.validate().is_ok());
+            prop_assert_eq!(a.par_mul_vec(&x, threads), a.mul_vec(&x));
+        }
+    }
+
+    // 行/列向量与稀疏矩阵-稀疏向量乘积, 普通存储和对称存储都要与稠密矩阵一致
+    #[test]
+    fn lines_and_sparse_mul_vec_match_dense(
+        (rows, cols, input, x) in (1..=7usize, 1..=7usize).prop_flat_map(|(r, c)| {
+            (Just(r), Just(c), matrix(r, c), prop::collection::vec(entry(), c))
+        })
+    ) {
+        let (m, dense) = build(input, rows, cols);
+        let mut layouts = vec![(m.clone(), dense.clone())];
+        if rows == cols {
+            let sym = m.add(&m.transpose());
+            let dense_sym = dense_add(&dense, &dense_transpose(&dense, rows, cols));
+            layouts.push((sym.to_symmetric_storage(), dense_sym));
+        }
+        for (m, dense) in layouts {
+            for i in 1..=rows {
+                prop_assert_eq!(m.row_vector(i), SparseVector::from_dense(&dense[i - 1]));
+            }
+            for j in 1..=cols {
+                let col: Vec<i32> = dense.iter().map(|row| row[j - 1]).collect();
+                prop_assert_eq!(m.col_vector(j), SparseVector::from_dense(&col));
+            }
+            let expected: Vec<i32> = dense.iter().map(|row| row.iter().zip(&x).map(|(a, b)| a * b).sum()).collect();
+            prop_assert_eq!(m.mul_sparse_vec(&SparseVector::from_dense(&x)), SparseVector::from_dense(&expected));
+        }
+    }
+
+    // 子矩阵的范围可以为空 (起点比终点大 1)
+    #[test]
+    fn submatrix_matches_dense(
+        (rows, cols, input, (r0, r1), (c0, c1)) in (1..=7usize, 1..=7usize).prop_flat_map(|(r, c)| {
+            let row_range = (1..=r).prop_flat_map(move |s| (Just(s), s - 1..=r));
+            let col_range = (1..=c).prop_flat_map(move |s| (Just(s), s - 1..=c));
+            (Just(r), Just(c), matrix(r, c), row_range, col_range)
+        })
+    ) {
+        let (m, dense) = build(input, rows, cols);
+        let row_idx: Vec<usize> = (r0..=r1).collect();
+        let col_idx: Vec<usize> = (c0..=c1).collect();
+        let expected = dense_select(&dense, &row_idx, &col_idx);
+        assert_matches(&m.submatrix(r0..=r1, c0..=c1), &expected, row_idx.len(), col_idx.len());
+        // 半开区间和无上界的写法
+        let tail: Vec<usize> = (c0..=cols).collect();
+        assert_matches(&m.submatrix(r0..r1 + 1, c0..), &dense_select(&dense, &row_idx, &tail), row_idx.len(), tail.len());
+    }
+
+    // 下标可以重复、乱序
+    #[test]
+    fn select_matches_dense(
+        (rows, cols, input, row_idx, col_idx) in (1..=7usize, 1..=7usize).prop_flat_map(|(r, c)| {
+            (
+                Just(r),
+                Just(c),
+                matrix(r, c),
+                prop::collection::vec(1..=r, 0..10),
+                prop::collection::vec(1..=c, 0..10),
+            )
+        })
+    ) {
+        let (m, dense) = build(input, rows, cols);
+        let expected = dense_select(&dense, &row_idx, &col_idx);
+        assert_matches(&m.select(&row_idx, &col_idx), &expected, row_idx.len(), col_idx.len());
+    }
+
+    #[test]
+    fn permutations_match_dense(
+        (rows, cols, input, row_perm, col_perm) in (dim(), dim()).prop_flat_map(|(r, c)| {
+            (Just(r), Just(c), matrix(r, c), permutation(r), permutation(c))
+        })
+    ) {
+        let (m, dense) = build(input, rows, cols);
+        let all_rows: Vec<usize> = (1..=rows).collect();
+        let all_cols: Vec<usize> = (1..=cols).collect();
+        assert_matches(&m.permute(&row_perm, &col_perm), &dense_select(&dense, &row_perm, &col_perm), rows, cols);
+        assert_matches(&m.permute_rows(&row_perm), &dense_select(&dense, &row_perm, &all_cols), rows, cols);
+        assert_matches(&m.permute_cols(&col_perm), &dense_select(&dense, &all_rows, &col_perm), rows, cols);
+    }
+
+    // 一串随机的 set (包括写 0 删除结点), 每一步后都与稠密矩阵对比
+    #[test]
+    fn set_and_get_match_dense(
+        (rows, cols, input, writes) in (1..=7usize, 1..=7usize).prop_flat_map(|(r, c)| {
+            (Just(r), Just(c), matrix(r, c), prop::collection::vec((1..=r, 1..=c, entry()), 0..20))
+        })
+    ) {
+        let (mut m, mut dense) = build(input, rows, cols);
+        for (i, j, v) in writes {
+            m.set(i, j, v);
+            dense[i - 1][j - 1] = v;
+            assert_matches(&m, &dense, rows, cols);
+        }
+    }
+
+    // extend 与逐个 set 等价: 同一坐标以最后一次为准, 写入 0 删除结点
+    #[test]
+    fn extend_matches_dense(
+        (rows, cols, input, writes) in (1..=7usize, 1..=7usize).prop_flat_map(|(r, c)| {
+            (Just(r), Just(c), matrix(r, c), prop::collection::vec((1..=r, 1..=c, entry()), 0..20))
+        })
+    ) {
+        let (mut m, mut dense) = build(input, rows, cols);
+        for &(i, j, v) in &writes {
+            dense[i - 1][j - 1] = v;
+        }
+        m.extend(writes);
+        assert_matches(&m, &dense, rows, cols);
+    }
+
+    // 随机的插入/删除整行整列序列; 位置按当前维数取模, 覆盖首尾以及追加到末尾 (rows + 1)
+    #[test]
+    fn row_and_col_edits_match_dense(
+        (rows, cols, input, edits) in (dim(), dim()).prop_flat_map(|(r, c)| {
+            (Just(r), Just(c), matrix(r, c), prop::collection::vec((0..4u8, any::<usize>()), 0..12))
+        })
+    ) {
+        let (mut m, mut dense) = build(input, rows, cols);
+        let (mut rows, mut cols) = (rows, cols);
+        for (op, pos) in edits {
+            match op {
+                0 => {
+                    let at = pos % (rows + 1) + 1;
+                    m.insert_row(at);
+                    dense.insert(at - 1, vec![0; cols]);
+                    rows += 1;
+                }
+                1 if rows > 0 => {
+                    let i = pos % rows + 1;
+                    m.delete_row(i);
+                    dense.remove(i - 1);
+                    rows -= 1;
+                }
+                2 => {
+                    let at = pos % (cols + 1) + 1;
+                    m.insert_col(at);
+                    dense.iter_mut().for_each(|row| row.insert(at - 1, 0));
+                    cols += 1;
+                }
+                3 if cols > 0 => {
+                    let j = pos % cols + 1;
+                    m.delete_col(j);
+                    dense.iter_mut().for_each(|row| { row.remove(j - 1); });
+                    cols -= 1;
+                }
+                _ => {}
+            }
+            assert_matches(&m, &dense, rows, cols);
+        }
+    }
+
+    // 值域偏向负数, 经常出现整行/整列都是负数 (最大值是负数) 的情形
+    #[test]
+    fn reductions_match_dense(
+        (rows, cols, dense) in (dim(), dim()).prop_flat_map(|(r, c)| {
+            let value = prop_oneof![1 => Just(0), 3 => -4..=-1i32, 1 => 1..=3i32];
+            (Just(r), Just(c), prop::collection::vec(prop::collection::vec(value, c), r))
+        })
+    ) {
+        let triplets = (1..=rows)
+            .flat_map(|i| (1..=cols).map(move |j| (i, j)))
+            .filter(|&(i, j)| dense[i - 1][j - 1] != 0)
+            .map(|(i, j)| (i, j, dense[i - 1][j - 1]))
+            .collect();
+        let m = SparseMatrix::from_triplets(rows, cols, triplets);
+        dense_reductions(&m, &dense, rows, cols);
+    }
+
+    #[test]
+    fn try_from_triplets_rejects_duplicates(
+        (rows, cols, input, pick) in (1..=7usize, 1..=7usize).prop_flat_map(|(r, c)| {
+            (Just(r), Just(c), matrix(r, c), any::<prop::sample::Index>())
+        })
+    ) {
+        let (_, mut triplets) = input;
+        prop_assume!(!triplets.is_empty());
+        let (r, c, v) = triplets[pick.index(triplets.len())];
+        triplets.push((r, c, v));
+        prop_assert_eq!(
+            SparseMatrix::try_from_triplets(rows, cols, triplets).err(),
+            Some(TripletError::Duplicate { row: r, col: c })
+        );
+    }
+}
+
+#[test]
+fn empty_matrices() {
+    for (rows, cols) in [(0, 0), (0, 3), (3, 0)] {
+        let m: SparseMatrix = SparseMatrix::new(rows, cols);
+        assert_matches(&m, &zeros(rows, cols), rows, cols);
+        assert_matches(&m.add(&m), &zeros(rows, cols), rows, cols);
+        assert_matches(&m.transpose(), &zeros(cols, rows), cols, rows);
+        assert_eq!(m.mul_vec(&vec![1; cols]), vec![0; rows]);
+    }
+    // 内维为 0 的乘积是全零矩阵
+    let a: SparseMatrix = SparseMatrix::new(2, 0);
+    let b: SparseMatrix = SparseMatrix::new(0, 3);
+    assert_matches(&a.multiply(&b), &zeros(2, 3), 2, 3);
+    for threads in [1, 2, 8] {
+        assert_matches(&a.par_multiply(&b, threads), &zeros(2, 3), 2, 3);
+        assert_matches(&b.par_add(&b, threads), &zeros(0, 3), 0, 3);
+        assert_eq!(a.par_mul_vec(&[], threads), vec![0, 0]);
+    }
+}
+
+#[test]
+fn one_by_one() {
+    let mut m = SparseMatrix::from_triplets(1, 1, vec![(1, 1, 5)]);
+    assert_matches(&m.multiply(&m), &vec![vec![25]], 1, 1);
+    assert_matches(&m.add(&SparseMatrix::from_triplets(1, 1, vec![(1, 1, -5)])), &zeros(1, 1), 1, 1);
+    m.set(1, 1, 0);
+    assert_matches(&m, &zeros(1, 1), 1, 1);
+}
+
+#[test]
+fn from_triplets_sums_duplicates() {
+    let m = SparseMatrix::from_triplets(2, 2, vec![(1, 1, 2), (2, 2, 1), (1, 1, 3), (2, 1, 4), (2, 1, -4)]);
+    assert_matches(&m, &vec![vec![5, 0], vec![0, 1]], 2, 2);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn from_triplets_panics_out_of_bounds() {
+    SparseMatrix::from_triplets(2, 2, vec![(3, 1, 1)]);
+}
+
+#[test]
+fn out_of_bounds_triplets_are_rejected() {
+    assert_eq!(
+        SparseMatrix::try_from_triplets(2, 3, vec![(1, 1, 1), (3, 1, 2)]).err(),
+        Some(TripletError::OutOfBounds { row: 3, col: 1, rows: 2, cols: 3 })
+    );
+    assert!(matches!(
+        SparseMatrix::try_from_triplets(2, 3, vec![(0, 1, 1)]),
+        Err(TripletError::OutOfBounds { row: 0, .. })
+    ));
+}
+
+#[test]
+fn collect_infers_dims_and_from_entries_fixes_them() {
+    let entries = vec![(2, 1, 4), (1, 2, 3), (2, 1, 5), (1, 1, 0)];
+    let inferred: SparseMatrix = entries.iter().copied().collect();
+    assert_matches(&inferred, &vec![vec![0, 3], vec![5, 0]], 2, 2);
+
+    let fixed = SparseMatrix::from_entries(3, 4, entries);
+    assert_matches(&fixed, &vec![vec![0, 3, 0, 0], vec![5, 0, 0, 0], vec![0; 4]], 3, 4);
+
+    let empty: SparseMatrix = std::iter::empty().collect();
+    assert_eq!(empty.dims(), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn from_entries_panics_out_of_bounds() {
+    SparseMatrix::from_entries(2, 2, vec![(1, 3, 1)]);
+}
+
+fn sample_3x4() -> SparseMatrix {
+    SparseMatrix::from_triplets(3, 4, vec![(1, 2, 1), (2, 4, 2), (3, 1, 3)])
+}
+
+#[test]
+#[should_panic(expected = "Column range out of bounds")]
+fn submatrix_panics_out_of_range() {
+    sample_3x4().submatrix(1..=3, 2..=5);
+}
+
+#[test]
+#[should_panic(expected = "Row index 4 out of bounds")]
+fn select_panics_out_of_range() {
+    sample_3x4().select(&[1, 4], &[1]);
+}
+
+#[test]
+#[should_panic(expected = "Column index 0 out of bounds")]
+fn select_panics_on_zero_index() {
+    sample_3x4().select(&[1], &[0]);
+}
+
+#[test]
+#[should_panic(expected = "Row permutation repeats index 2")]
+fn permute_rows_rejects_repeated_index() {
+    sample_3x4().permute_rows(&[2, 1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "Column permutation has length 3, expected 4")]
+fn permute_cols_rejects_wrong_length() {
+    sample_3x4().permute_cols(&[1, 2, 3]);
+}
+
+#[test]
+fn append_and_delete_last_row_and_col() {
+    let mut m = sample_3x4();
+    m.insert_row(4);
+    m.insert_col(5);
+    m.set(4, 5, 9);
+    assert_matches(
+        &m,
+        &vec![vec![0, 1, 0, 0, 0], vec![0, 0, 0, 2, 0], vec![3, 0, 0, 0, 0], vec![0, 0, 0, 0, 9]],
+        4,
+        5,
+    );
+    m.delete_row(4);
+    m.delete_col(5);
+    m.delete_col(4);
+    assert_matches(&m, &vec![vec![0, 1, 0], vec![0, 0, 0], vec![3, 0, 0]], 3, 3);
+}
+
+#[test]
+#[should_panic(expected = "Row insertion point 5 out of bounds (valid positions are 1..=4)")]
+fn insert_row_panics_past_end() {
+    sample_3x4().insert_row(5);
+}
+
+#[test]
+#[should_panic(expected = "Column insertion point 0 out of bounds")]
+fn insert_col_panics_at_zero() {
+    sample_3x4().insert_col(0);
+}
+
+#[test]
+#[should_panic(expected = "Row index 4 out of bounds (valid indices are 1..=3)")]
+fn delete_row_panics_out_of_range() {
+    sample_3x4().delete_row(4);
+}
+
+#[test]
+#[should_panic(expected = "Column index 1 out of bounds")]
+fn delete_col_panics_on_empty_matrix() {
+    SparseMatrix::<i32>::new(2, 0).delete_col(1);
+}
+
+#[test]
+#[should_panic(expected = "delete_row is not supported in symmetric storage")]
+fn row_edits_panic_in_symmetric_storage() {
+    let m = SparseMatrix::from_triplets(2, 2, vec![(1, 1, 1), (1, 2, 2), (2, 1, 2)]);
+    m.to_symmetric_storage().delete_row(1);
+}
+
+#[test]
+fn reductions_known_answers() {
+    // 第 2 行全空, 第 3 行全为负数, 第 2 列全空
+    let m = SparseMatrix::from_triplets(3, 3, vec![(1, 1, 4), (1, 3, -1), (3, 1, -2), (3, 2, -5), (3, 3, -2)]);
+    assert_eq!(m.row_sums(), vec![3, 0, -9]);
+    assert_eq!(m.col_sums(), vec![2, -5, -3]);
+    assert_eq!(m.row_max(), vec![Some((1, 4)), Some((1, 0)), Some((1, -2))]);
+    assert_eq!(m.col_max(), vec![Some((1, 4)), Some((1, 0)), Some((2, 0))]);
+    assert_eq!(m.diagonal(), vec![4, 0, -2]);
+    assert_eq!(m.trace(), 2);
+    assert_eq!(m.norm_1(), 6);
+    assert_eq!(m.norm_inf(), 9);
+    assert_eq!(m.norm_frobenius(), 50f64.sqrt());
+
+    // 非方阵的对角线长度为 min(rows, cols); 没有列时各行最大值为 None
+    let wide = SparseMatrix::from_triplets(2, 4, vec![(2, 2, 7), (2, 4, 1)]);
+    assert_eq!(wide.diagonal(), vec![0, 7]);
+    let no_cols: SparseMatrix = SparseMatrix::new(2, 0);
+    assert_eq!(no_cols.row_max(), vec![None, None]);
+    assert_eq!(no_cols.col_max(), vec![]);
+    assert_eq!(no_cols.norm_1(), 0);
+}
@@ -0,0 +1,97 @@
+use std::f64::consts::PI;
+
+use sparse_matrix_proj::{EigenError, EigenOptions, SparseMatrix, Which};
+
+// tridiagonal(-1, 2, -1) 的特征值为 2 - 2cos(kπ/(n + 1)), k = 1..=n
+fn laplacian_1d(n: usize) -> (SparseMatrix<f64>, Vec<f64>) {
+    let exact = (1..=n).map(|k| 2.0 - 2.0 * (k as f64 * PI / (n + 1) as f64).cos()).collect();
+    (SparseMatrix::tridiagonal(n, -1.0, 2.0, -1.0), exact)
+}
+
+fn assert_eigenpair(a: &SparseMatrix<f64>, value: f64, vector: &[f64]) {
+    let ax = a.mul_vec(vector);
+    let residual: f64 = ax.iter().zip(vector).map(|(y, x)| (y - value * x).powi(2)).sum::<f64>().sqrt();
+    assert!(residual < 1e-6, "residual {} for eigenvalue {}", residual, value);
+}
+
+#[test]
+fn lanczos_finds_both_ends_of_the_spectrum() {
+    let (a, exact) = laplacian_1d(30);
+    let opts = EigenOptions::default();
+
+    let smallest = a.lanczos(3, Which::SmallestAlgebraic, &opts);
+    assert!(smallest.converged);
+    for (i, (&value, vector)) in smallest.values.iter().zip(&smallest.vectors).enumerate() {
+        assert!((value - exact[i]).abs() < 1e-8, "{} vs {}", value, exact[i]);
+        assert_eigenpair(&a, value, vector);
+    }
+
+    let largest = a.lanczos(2, Which::LargestAlgebraic, &opts);
+    assert!(largest.converged);
+    assert!((largest.values[0] - exact[29]).abs() < 1e-8);
+    assert!((largest.values[1] - exact[28]).abs() < 1e-8);
+}
+
+#[test]
+fn power_and_inverse_iteration() {
+    let d = SparseMatrix::from_diagonal(&[1.0, -7.0, 3.0, 2.0]);
+    let result = d.power_iteration(&EigenOptions::default());
+    assert!(result.converged);
+    assert!((result.value + 7.0).abs() < 1e-9);
+    assert!(result.vector[1].abs() > 1.0 - 1e-6);
+
+    let (a, exact) = laplacian_1d(10);
+    let result = a.inverse_iteration(0.0, &EigenOptions::default()).unwrap();
+    assert!(result.converged);
+    assert!((result.value - exact[0]).abs() < 1e-9);
+    assert_eigenpair(&a, result.value, &result.vector);
+}
+
+#[test]
+fn inverse_iteration_with_interior_shift() {
+    // A - 6I 不定, 收敛到离 6 最近的 5
+    let d = SparseMatrix::from_diagonal(&[1.0, 5.0, 10.0]);
+    let result = d.inverse_iteration(6.0, &EigenOptions::default()).unwrap();
+    assert!(result.converged);
+    assert!((result.value - 5.0).abs() < 1e-9, "{}", result.value);
+    assert!(result.vector[1].abs() > 1.0 - 1e-6);
+
+    let (a, exact) = laplacian_1d(20);
+    let shift = (exact[9] + exact[10]) / 2.0 - 0.01;
+    let result = a.inverse_iteration(shift, &EigenOptions::default()).unwrap();
+    assert!(result.converged);
+    assert!((result.value - exact[9]).abs() < 1e-9, "{} vs {}", result.value, exact[9]);
+    assert_eigenpair(&a, result.value, &result.vector);
+
+    // 对称存储与一般存储结果相同
+    let stored = a.to_symmetric_storage().inverse_iteration(shift, &EigenOptions::default()).unwrap();
+    assert!((stored.value - result.value).abs() < 1e-9);
+}
+
+#[test]
+fn inverse_iteration_needs_row_pivoting() {
+    // 主对角线全为 0, 不换行无法消去; 特征值为 ±1 与 2
+    let a = SparseMatrix::from_triplets(3, 3, vec![(1, 2, 1.0), (2, 1, 1.0), (3, 3, 2.0)]);
+    let result = a.inverse_iteration(0.3, &EigenOptions::default()).unwrap();
+    assert!(result.converged);
+    assert!((result.value - 1.0).abs() < 1e-9, "{}", result.value);
+    assert_eigenpair(&a, result.value, &result.vector);
+
+    let result = a.inverse_iteration(-0.8, &EigenOptions::default()).unwrap();
+    assert!(result.converged);
+    assert!((result.value + 1.0).abs() < 1e-9, "{}", result.value);
+}
+
+#[test]
+fn inverse_iteration_errors() {
+    let d = SparseMatrix::from_diagonal(&[1.0, 5.0, 10.0]);
+    assert_eq!(
+        d.inverse_iteration(5.0, &EigenOptions::default()).err(),
+        Some(EigenError::SingularShift { shift: 5.0, col: 2 })
+    );
+    let rectangular = SparseMatrix::<f64>::new(2, 3);
+    assert_eq!(
+        rectangular.inverse_iteration(0.0, &EigenOptions::default()).err(),
+        Some(EigenError::NotSquare { rows: 2, cols: 3 })
+    );
+}
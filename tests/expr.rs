@@ -0,0 +1,73 @@
+use sparse_matrix_proj::{CalcError, Session, SparseMatrix};
+
+// A: 2x3, B: 2x3, C: 3x3, 取值让不同的结合方式得到不同结果
+fn operands() -> (SparseMatrix, SparseMatrix, SparseMatrix) {
+    let a = SparseMatrix::from_triplets(2, 3, vec![(1, 1, 1), (1, 3, 2), (2, 2, -3)]);
+    let b = SparseMatrix::from_triplets(2, 3, vec![(1, 1, 4), (2, 1, 5), (2, 3, 1)]);
+    let c = SparseMatrix::from_triplets(3, 3, vec![(1, 2, 2), (2, 2, 1), (3, 1, -1), (3, 3, 7)]);
+    (a, b, c)
+}
+
+// 参照实现只用 add/multiply/transpose, 减法写成加上取负的矩阵
+fn neg(m: &SparseMatrix) -> SparseMatrix {
+    let (rows, cols) = m.dims();
+    SparseMatrix::from_triplets(rows, cols, m.iter().map(|(r, c, v)| (r, c, -v)).collect())
+}
+
+fn sub(a: &SparseMatrix, b: &SparseMatrix) -> SparseMatrix {
+    a.add(&neg(b))
+}
+
+fn session() -> Session {
+    let (a, b, c) = operands();
+    let mut s = Session::new();
+    s.insert("A", a);
+    s.insert("B", b);
+    s.insert("C", c);
+    s
+}
+
+#[test]
+fn fused_eval_matches_eager_operations() {
+    let (a, b, c) = operands();
+    assert_eq!((&a + &b * &c).eval(), a.add(&b.multiply(&c)));
+    assert_eq!((&a - &b - &a).eval(), sub(&sub(&a, &b), &a));
+    assert_eq!((-(&a - &b) * &c).eval(), sub(&b, &a).multiply(&c));
+    // 相消的元素不存储
+    assert_eq!((&a - &a).eval().iter().len(), 0);
+}
+
+#[test]
+fn parser_precedence_and_associativity() {
+    let (a, b, c) = operands();
+    let s = session();
+    // * 优先于 +/-, +/- 左结合
+    assert_eq!(s.eval("A + B * C").unwrap(), a.add(&b.multiply(&c)));
+    assert_eq!(s.eval("A - B - A").unwrap(), sub(&sub(&a, &b), &a));
+    assert_eq!(s.eval("(A - B) * C").unwrap(), sub(&a, &b).multiply(&c));
+    // 一元负号作用于紧随的因子
+    assert_eq!(s.eval("-A * C").unwrap(), neg(&a).multiply(&c));
+    assert_eq!(s.eval("B - -A").unwrap(), b.add(&a));
+}
+
+#[test]
+fn parser_transpose() {
+    let (a, b, c) = operands();
+    let s = session();
+    // 转置比乘法结合得更紧
+    assert_eq!(s.eval("A * B'").unwrap(), a.multiply(&b.transpose()));
+    assert_eq!(s.eval("(A + B)'").unwrap(), a.add(&b).transpose());
+    assert_eq!(s.eval("A''").unwrap(), a);
+    assert_eq!(s.eval("C' * A'").unwrap(), a.multiply(&c).transpose());
+}
+
+#[test]
+fn parser_errors() {
+    let s = session();
+    for src in ["A +", "(A", "A B", "A)", "*A", ""] {
+        assert!(matches!(s.eval(src), Err(CalcError::Syntax(_))), "{:?}", src);
+    }
+    assert!(matches!(s.eval("A + X"), Err(CalcError::UnknownMatrix(name)) if name == "X"));
+    assert!(matches!(s.eval("A * B"), Err(CalcError::DimensionMismatch(_))));
+    assert!(matches!(s.eval("A + C"), Err(CalcError::DimensionMismatch(_))));
+}
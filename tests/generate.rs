@@ -0,0 +1,107 @@
+use proptest::prelude::*;
+use sparse_matrix_proj::SparseMatrix;
+
+type Entries = Vec<(usize, usize, i32)>;
+
+fn entries(m: &SparseMatrix) -> Entries {
+    assert!(m.validate().is_ok());
+    m.iter().collect()
+}
+
+fn in_bounds(m: &SparseMatrix) -> bool {
+    let (rows, cols) = m.dims();
+    m.iter().all(|(r, c, _)| (1..=rows).contains(&r) && (1..=cols).contains(&c))
+}
+
+proptest! {
+    #[test]
+    fn random_uniform_has_the_requested_count(rows in 0..30usize, cols in 0..30usize, density in 0.0..=1.0f64, seed: u64) {
+        let m: SparseMatrix = SparseMatrix::random_uniform(rows, cols, density, seed);
+        let expected = (density * (rows * cols) as f64).round() as usize;
+        let e = entries(&m);
+        prop_assert_eq!(m.dims(), (rows, cols));
+        prop_assert_eq!(e.len(), expected);
+        prop_assert!(in_bounds(&m));
+        prop_assert!(e.iter().all(|&(_, _, v)| (1..=9).contains(&v)));
+        // 同一种子得到同一矩阵
+        prop_assert_eq!(e, entries(&SparseMatrix::random_uniform(rows, cols, density, seed)));
+    }
+
+    #[test]
+    fn rmat_is_deterministic_and_in_bounds(scale in 0..12u32, edges in 0..200usize, seed: u64) {
+        let m: SparseMatrix = SparseMatrix::rmat(scale, edges, 0.57, 0.19, 0.19, seed);
+        let n = 1 << scale;
+        let e = entries(&m);
+        prop_assert_eq!(m.dims(), (n, n));
+        // 重复的边只保留一次
+        prop_assert!(e.len() <= edges.min(n * n));
+        prop_assert!(edges == 0 || !e.is_empty());
+        prop_assert!(in_bounds(&m));
+        prop_assert!(e.iter().all(|&(_, _, v)| v == 1));
+        prop_assert_eq!(e, entries(&SparseMatrix::rmat(scale, edges, 0.57, 0.19, 0.19, seed)));
+    }
+}
+
+#[test]
+fn random_uniform_known_cases() {
+    assert!(entries(&SparseMatrix::random_uniform(4, 5, 0.0, 1)).is_empty());
+    let full: SparseMatrix = SparseMatrix::random_uniform(4, 5, 1.0, 1);
+    let positions: Vec<(usize, usize)> = entries(&full).into_iter().map(|(r, c, _)| (r, c)).collect();
+    let all: Vec<(usize, usize)> = (1..=4).flat_map(|r| (1..=5).map(move |c| (r, c))).collect();
+    assert_eq!(positions, all);
+    assert_eq!(entries(&SparseMatrix::random_uniform(10, 10, 0.25, 3)).len(), 25);
+    assert_ne!(
+        entries(&SparseMatrix::random_uniform(20, 20, 0.3, 1)),
+        entries(&SparseMatrix::random_uniform(20, 20, 0.3, 2))
+    );
+}
+
+// 内存只与非零元个数有关, 维数可以远大于元素个数
+#[test]
+fn random_uniform_large_dims() {
+    let n = 1 << 30;
+    let m: SparseMatrix = SparseMatrix::random_uniform(n, n, 1e-15, 7);
+    let expected = (1e-15 * (n as f64) * (n as f64)).round() as usize;
+    assert_eq!(entries(&m).len(), expected);
+    assert!(in_bounds(&m));
+}
+
+#[test]
+fn rmat_known_cases() {
+    // a = 1: 每条边都落在左上角
+    let corner: SparseMatrix = SparseMatrix::rmat(5, 50, 1.0, 0.0, 0.0, 9);
+    assert_eq!(entries(&corner), vec![(1, 1, 1)]);
+    // d = 1: 每条边都落在右下角
+    let far: SparseMatrix = SparseMatrix::rmat(5, 50, 0.0, 0.0, 0.0, 9);
+    assert_eq!(entries(&far), vec![(32, 32, 1)]);
+    // b = 1: 第一行, 最后一列
+    let right: SparseMatrix = SparseMatrix::rmat(3, 10, 0.0, 1.0, 0.0, 9);
+    assert_eq!(entries(&right), vec![(1, 8, 1)]);
+    assert_ne!(
+        entries(&SparseMatrix::rmat(8, 300, 0.57, 0.19, 0.19, 1)),
+        entries(&SparseMatrix::rmat(8, 300, 0.57, 0.19, 0.19, 2))
+    );
+}
+
+#[test]
+fn rmat_large_scale() {
+    let m: SparseMatrix = SparseMatrix::rmat(40, 2000, 0.45, 0.15, 0.15, 11);
+    assert_eq!(m.dims(), (1 << 40, 1 << 40));
+    let e = entries(&m);
+    // 两条边落在同一位置的概率为 (a² + b² + c² + d²)^40 ≈ 4e-21, 这个种子下没有重复
+    assert_eq!(e.len(), 2000);
+    assert!(in_bounds(&m));
+    assert_eq!(e, entries(&SparseMatrix::rmat(40, 2000, 0.45, 0.15, 0.15, 11)));
+}
+
+#[test]
+#[should_panic(expected = "Density 1.5 must lie in [0, 1]")]
+fn random_uniform_rejects_bad_density() {
+    let _: SparseMatrix = SparseMatrix::random_uniform(3, 3, 1.5, 0);
+}
+
+#[test]
+#[should_panic(expected = "R-MAT probabilities must be non-negative and sum to at most 1")]
+fn rmat_rejects_bad_probabilities() {
+    let _: SparseMatrix = SparseMatrix::rmat(3, 3, 0.6, 0.3, 0.3, 0);
+}
@@ -0,0 +1,50 @@
+use sparse_matrix_proj::{ReadError, SparseMatrix};
+
+fn read_mm(text: &str) -> Result<SparseMatrix, ReadError> {
+    SparseMatrix::read_matrix_market(text.as_bytes())
+}
+
+fn read_triplets(text: &str) -> Result<SparseMatrix, ReadError> {
+    SparseMatrix::read_triplets(text.as_bytes())
+}
+
+fn parse_error(result: Result<SparseMatrix, ReadError>) -> (usize, String) {
+    match result {
+        Err(ReadError::Parse { line, message }) => (line, message),
+        other => panic!("expected a parse error, got {:?}", other.map(|m| m.dims())),
+    }
+}
+
+#[test]
+fn matrix_market_round_trip() {
+    let m = SparseMatrix::from_triplets(3, 2, vec![(1, 2, -4), (3, 1, 7)]);
+    let mut text = Vec::new();
+    m.write_matrix_market(&mut text).unwrap();
+    assert_eq!(read_mm(std::str::from_utf8(&text).unwrap()).unwrap(), m);
+
+    let symmetric = "%%MatrixMarket matrix coordinate integer symmetric\n% comment\n2 2 2\n1 1 3\n2 1 5\n";
+    assert_eq!(read_mm(symmetric).unwrap().iter().collect::<Vec<_>>(), vec![(1, 1, 3), (1, 2, 5), (2, 1, 5)]);
+}
+
+// 多余的字段与 read_triplets 一样报错, 不静默忽略
+#[test]
+fn matrix_market_rejects_trailing_tokens() {
+    let header = "%%MatrixMarket matrix coordinate integer general\n";
+    let (line, message) = parse_error(read_mm(&format!("{}2 2 1 extra\n1 1 2\n", header)));
+    assert_eq!((line, message.as_str()), (2, "unexpected trailing 'extra'"));
+
+    let (line, message) = parse_error(read_mm(&format!("{}2 2 1\n1 1 2 extra\n", header)));
+    assert_eq!((line, message.as_str()), (3, "unexpected trailing 'extra'"));
+
+    // pattern 矩阵的元素行只有两个字段
+    let pattern = "%%MatrixMarket matrix coordinate pattern general\n2 2 1\n";
+    assert_eq!(read_mm(&format!("{}2 1\n", pattern)).unwrap().get(2, 1), 1);
+    assert_eq!(parse_error(read_mm(&format!("{}2 1 1\n", pattern))).0, 3);
+}
+
+#[test]
+fn triplets_reject_trailing_tokens() {
+    assert_eq!(read_triplets("# c\n2 2\n1 2 5\n").unwrap().get(1, 2), 5);
+    assert_eq!(parse_error(read_triplets("2 2 9\n1 2 5\n")), (1, "unexpected trailing '9'".to_string()));
+    assert_eq!(parse_error(read_triplets("2 2\n1 2 5 6\n")), (2, "unexpected trailing '6'".to_string()));
+}
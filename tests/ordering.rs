@@ -0,0 +1,53 @@
+use sparse_matrix_proj::{Ordering, SparseMatrix};
+
+fn assert_permutation(ordering: &Ordering, n: usize) {
+    let mut sorted = ordering.perm.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (1..=n).collect::<Vec<_>>());
+}
+
+// 把路径图的结点编号打乱, RCM 应当恢复出带宽为 1 的排列
+#[test]
+fn rcm_recovers_a_scrambled_path() {
+    let n = 12;
+    let path: SparseMatrix = SparseMatrix::tridiagonal(n, -1, 2, -1);
+    let scramble: Vec<usize> = (0..n).map(|k| (k * 5) % n + 1).collect();
+    let scrambled = path.permute(&scramble, &scramble);
+
+    let ordering = scrambled.rcm_ordering();
+    assert_permutation(&ordering, n);
+    assert!(ordering.bandwidth_before > 1);
+    assert_eq!(ordering.bandwidth_after, 1);
+    assert_eq!(scrambled.permute(&ordering.perm, &ordering.perm).stats().lower_bandwidth, 1);
+    assert!(ordering.profile_after < ordering.profile_before);
+}
+
+// 星形图 (箭头矩阵): 中心结点最先消去会让 L 变成稠密下三角; AMD 应把它留到只剩一个邻居时
+// (最后两位之一) 再消去, 完全没有填充
+#[test]
+fn amd_eliminates_the_hub_last() {
+    let n = 8;
+    let mut entries = vec![(1, 1, n as i32)];
+    for i in 2..=n {
+        entries.push((1, i, -1));
+        entries.push((i, 1, -1));
+        entries.push((i, i, n as i32));
+    }
+    let arrow: SparseMatrix = SparseMatrix::from_triplets(n, n, entries);
+    assert_eq!(arrow.symbolic_cholesky().nnz(), n * (n + 1) / 2);
+
+    let ordering = arrow.amd_ordering();
+    assert_permutation(&ordering, n);
+    assert!(ordering.perm[n - 2..].contains(&1));
+    let reordered = arrow.permute(&ordering.perm, &ordering.perm);
+    assert_eq!(reordered.symbolic_cholesky().nnz(), 2 * n - 1);
+}
+
+#[test]
+fn orderings_of_trivial_matrices() {
+    for n in [0, 1] {
+        let m: SparseMatrix = SparseMatrix::identity(n);
+        assert_eq!(m.rcm_ordering().perm, (1..=n).collect::<Vec<_>>());
+        assert_eq!(m.amd_ordering().perm, (1..=n).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,68 @@
+use sparse_matrix_proj::{PageRankOptions, SparseMatrix};
+
+fn assert_close(a: &[f64], b: &[f64], tol: f64) {
+    assert_eq!(a.len(), b.len());
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() < tol, "node {}: {} vs {}", i + 1, x, y);
+    }
+}
+
+// 稠密 Google 矩阵 G = d·(M + v·dᵀ) + (1 - d)·v·1ᵀ 上的幂迭代, 作为参照
+fn dense_pagerank(links: &[Vec<f64>], damping: f64) -> Vec<f64> {
+    let n = links.len();
+    let totals: Vec<f64> = (0..n).map(|j| (0..n).map(|i| links[i][j]).sum()).collect();
+    let g: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    let walk = if totals[j] == 0.0 { 1.0 / n as f64 } else { links[i][j] / totals[j] };
+                    damping * walk + (1.0 - damping) / n as f64
+                })
+                .collect()
+        })
+        .collect();
+    let mut x = vec![1.0 / n as f64; n];
+    for _ in 0..2000 {
+        x = g.iter().map(|row| row.iter().zip(&x).map(|(a, b)| a * b).sum()).collect();
+    }
+    x
+}
+
+// A(i, j) 为 j -> i 的链接; 结点 4 没有出链
+#[test]
+fn matches_dense_reference() {
+    let links = vec![
+        vec![0.0, 1.0, 0.0, 0.0],
+        vec![2.0, 0.0, 0.0, 0.0],
+        vec![1.0, 1.0, 0.0, 0.0],
+        vec![0.0, 0.0, 1.0, 0.0],
+    ];
+    let triplets = (1..=4)
+        .flat_map(|i| (1..=4).map(move |j| (i, j)))
+        .filter(|&(i, j)| links[i - 1][j - 1] != 0.0)
+        .map(|(i, j)| (i, j, links[i - 1][j - 1]))
+        .collect();
+    let a = SparseMatrix::from_triplets(4, 4, triplets);
+    assert_eq!(a.dangling_nodes(), vec![4]);
+
+    let result = a.pagerank(&PageRankOptions::default());
+    assert!(result.converged);
+    assert!((result.ranks.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    assert_close(&result.ranks, &dense_pagerank(&links, 0.85), 1e-9);
+}
+
+#[test]
+fn closed_form_answers() {
+    // 有向环上各结点地位相同
+    let cycle: SparseMatrix = SparseMatrix::from_triplets(3, 3, vec![(2, 1, 1), (3, 2, 1), (1, 3, 1)]);
+    assert_close(&cycle.pagerank(&PageRankOptions::default()).ranks, &[1.0 / 3.0; 3], 1e-12);
+
+    // 1 -> 2, 结点 2 悬挂: x1 = 0.425·x2 + 0.075, x1 + x2 = 1
+    let edge: SparseMatrix = SparseMatrix::from_triplets(2, 2, vec![(2, 1, 1)]);
+    let x1 = 0.5 / 1.425;
+    assert_close(&edge.pagerank(&PageRankOptions::default()).ranks, &[x1, 1.0 - x1], 1e-9);
+
+    // 不阻尼时个性化 PageRank 就是跳转分布本身
+    let opts = PageRankOptions { damping: 0.0, ..PageRankOptions::default() };
+    assert_close(&edge.personalized_pagerank(&[3.0, 1.0], &opts).ranks, &[0.75, 0.25], 1e-12);
+}
@@ -0,0 +1,44 @@
+// 非 i32 元素类型: 规约、稠密转换、向量和文本格式都应按 T 工作
+use sparse_matrix_proj::{DenseMatrix, ReadError, SparseMatrix, SparseVector};
+
+fn sample() -> SparseMatrix<f64> {
+    SparseMatrix::from_triplets(2, 3, vec![(1, 1, 1.5), (1, 3, -2.0), (2, 2, 0.25)])
+}
+
+#[test]
+fn reductions_and_dense() {
+    let m = sample();
+    assert_eq!(m.row_sums(), vec![-0.5, 0.25]);
+    assert_eq!(m.norm_1(), 2.0);
+    assert_eq!(m.norm_inf(), 3.5);
+    assert_eq!(m.to_dense().row(1), &[1.5, 0.0, -2.0]);
+
+    let dense = DenseMatrix::from_rows(vec![vec![1e-9, 2.0], vec![0.0, -3.0]]);
+    let dropped = SparseMatrix::from_dense_with_tolerance(&dense, 1e-6);
+    assert_eq!(dropped.iter().collect::<Vec<_>>(), vec![(1, 2, 2.0), (2, 2, -3.0)]);
+
+    let v = SparseVector::from_dense(&[0.0, 3.0, -4.0]);
+    assert_eq!(v.norm_2(), 5.0);
+    assert_eq!(m.mul_sparse_vec(&v).to_dense(), vec![8.0, 0.75]);
+}
+
+#[test]
+fn matrix_market_round_trip() {
+    let m = sample();
+    let mut text = Vec::new();
+    m.write_matrix_market(&mut text).unwrap();
+    assert!(String::from_utf8_lossy(&text).starts_with("%%MatrixMarket matrix coordinate real general"));
+    assert_eq!(SparseMatrix::<f64>::read_matrix_market(text.as_slice()).unwrap(), m);
+
+    // 整数矩阵读 real 文件时, 不能精确表示的值报错而不是截断
+    assert!(matches!(SparseMatrix::<i32>::read_matrix_market(text.as_slice()), Err(ReadError::Parse { .. })));
+}
+
+#[test]
+fn dense_and_sparse_print_identically() {
+    let m = sample();
+    let (mut sparse, mut dense) = (Vec::new(), Vec::new());
+    m.write_dense(&mut sparse).unwrap();
+    m.to_dense().write_dense(&mut dense).unwrap();
+    assert_eq!(String::from_utf8(sparse).unwrap(), String::from_utf8(dense).unwrap());
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "serde")]
+
+use sparse_matrix_proj::SparseMatrix;
+
+fn round_trip(m: &SparseMatrix) -> SparseMatrix {
+    let json = serde_json::to_string(m).unwrap();
+    let back: SparseMatrix = serde_json::from_str(&json).unwrap();
+    assert!(back.validate().is_ok());
+    back
+}
+
+fn parse(json: &str) -> Result<SparseMatrix, String> {
+    serde_json::from_str(json).map_err(|e| e.to_string())
+}
+
+#[test]
+fn general_round_trip() {
+    let m = SparseMatrix::from_triplets(3, 4, vec![(1, 1, 2), (1, 4, -1), (3, 2, 7)]);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, r#"{"rows":3,"cols":4,"triplets":[[1,1,2],[1,4,-1],[3,2,7]]}"#);
+    let back = round_trip(&m);
+    assert_eq!(back, m);
+    assert!(!back.is_symmetric_storage());
+
+    let empty = SparseMatrix::<i32>::new(2, 5);
+    assert_eq!(round_trip(&empty), empty);
+}
+
+#[test]
+fn symmetric_round_trip_keeps_storage() {
+    let m = SparseMatrix::from_symmetric_triplets(3, vec![(1, 1, 4), (2, 1, -2), (3, 2, 5), (3, 3, 1)]);
+    let json = serde_json::to_string(&m).unwrap();
+    // 只写出下三角
+    assert_eq!(json, r#"{"rows":3,"cols":3,"symmetric":true,"triplets":[[1,1,4],[2,1,-2],[3,2,5],[3,3,1]]}"#);
+    let back = round_trip(&m);
+    assert!(back.is_symmetric_storage());
+    assert_eq!(back, m);
+    assert_eq!(back.get(1, 2), -2);
+}
+
+#[test]
+fn zero_values_are_dropped() {
+    let m = parse(r#"{"rows":2,"cols":2,"triplets":[[1,1,0],[2,2,3]]}"#).unwrap();
+    assert!(m.validate().is_ok());
+    assert_eq!(m.iter().collect::<Vec<_>>(), vec![(2, 2, 3)]);
+}
+
+#[test]
+fn rejects_out_of_bounds_entries() {
+    for json in [
+        r#"{"rows":2,"cols":2,"triplets":[[3,1,1]]}"#,
+        r#"{"rows":2,"cols":2,"triplets":[[1,3,1]]}"#,
+        r#"{"rows":2,"cols":2,"triplets":[[0,1,1]]}"#,
+        r#"{"rows":2,"cols":2,"triplets":[[1,0,1]]}"#,
+    ] {
+        let err = parse(json).unwrap_err();
+        assert!(err.contains("lies outside the 2x2 matrix"), "{}: {}", json, err);
+    }
+}
+
+#[test]
+fn rejects_duplicate_entries() {
+    let err = parse(r#"{"rows":2,"cols":2,"triplets":[[1,2,1],[1,2,5]]}"#).unwrap_err();
+    assert!(err.contains("entry (1, 2) is given more than once"), "{}", err);
+}
+
+#[test]
+fn rejects_unsorted_entries() {
+    for json in [
+        r#"{"rows":2,"cols":2,"triplets":[[2,1,1],[1,2,1]]}"#,
+        r#"{"rows":2,"cols":2,"triplets":[[1,2,1],[1,1,1]]}"#,
+    ] {
+        let err = parse(json).unwrap_err();
+        assert!(err.contains("row-major order"), "{}: {}", json, err);
+    }
+}
+
+#[test]
+fn rejects_malformed_symmetric_storage() {
+    let err = parse(r#"{"rows":2,"cols":3,"symmetric":true,"triplets":[]}"#).unwrap_err();
+    assert!(err.contains("square"), "{}", err);
+    let err = parse(r#"{"rows":2,"cols":2,"symmetric":true,"triplets":[[1,2,1]]}"#).unwrap_err();
+    assert!(err.contains("above the diagonal"), "{}", err);
+}
@@ -0,0 +1,65 @@
+use sparse_matrix_proj::{SparseMatrix, SpyStyle};
+
+// 2x8: 第 1 行 1..=3 列, 第 2 行第 5, 8 列
+fn wide() -> SparseMatrix {
+    SparseMatrix::from_triplets(2, 8, vec![(1, 1, 1), (1, 2, 1), (1, 3, 1), (2, 5, 1), (2, 8, 1)])
+}
+
+fn svg(m: &SparseMatrix, width: usize, height: usize) -> String {
+    let mut out = Vec::new();
+    m.write_spy_svg(&mut out, width, height).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn known_pattern() {
+    let id: SparseMatrix = SparseMatrix::identity(4);
+    assert_eq!(id.spy(4, 4, SpyStyle::Ascii), "+----+\n|#   |\n| #  |\n|  # |\n|   #|\n+----+\n");
+    // 2x2 个格子, 每格 4 个位置中有 2 个非零元
+    assert_eq!(id.spy(2, 2, SpyStyle::Ascii), "+--+\n|* |\n| *|\n+--+\n");
+    // 一个盲文字符是 2x4 个点: (1,1) (2,2) 在左列上两点, (3,3) (4,4) 在右列下两点
+    assert_eq!(id.spy(1, 1, SpyStyle::Braille), "+-+\n|\u{28a3}|\n+-+\n");
+}
+
+// 非方阵: 高度被行数截断为 2, 每格覆盖 2 列
+#[test]
+fn non_square_aspect_ratio() {
+    assert_eq!(wide().spy(4, 4, SpyStyle::Ascii), "+----+\n|#*  |\n|  **|\n+----+\n");
+
+    let out = svg(&wide(), 4, 4);
+    assert!(out.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="8" viewBox="0 0 4 2""#));
+    assert!(out.contains("<title>2x8 sparse matrix, nnz = 5</title>"));
+    assert!(out.contains(r#"<rect x="0" y="0" width="1" height="1" fill="black" fill-opacity="1.000"/>"#));
+    assert!(out.contains(r#"<rect x="3" y="1" width="1" height="1" fill="black" fill-opacity="0.650"/>"#));
+    assert_eq!(out.matches("fill=\"black\"").count(), 4);
+    assert!(out.ends_with("</svg>\n"));
+}
+
+#[test]
+fn empty_matrices() {
+    let empty: SparseMatrix = SparseMatrix::new(0, 0);
+    assert_eq!(empty.spy(3, 3, SpyStyle::Ascii), "+-+\n| |\n+-+\n");
+    assert_eq!(empty.spy(3, 3, SpyStyle::Braille), "+-+\n|\u{2800}|\n+-+\n");
+    let zeros: SparseMatrix = SparseMatrix::new(3, 5);
+    assert_eq!(zeros.spy(3, 3, SpyStyle::Ascii), "+---+\n|   |\n|   |\n|   |\n+---+\n");
+
+    let out = svg(&empty, 3, 3);
+    assert!(out.contains("<title>0x0 sparse matrix, nnz = 0</title>"));
+    assert!(!out.contains("fill=\"black\""));
+}
+
+// 耗时与维数无关, 下标换算也不会溢出
+#[test]
+fn huge_dimensions() {
+    let n = 1usize << 60;
+    let mut m: SparseMatrix = SparseMatrix::new(n, n);
+    m.set(n, 1, 1);
+    m.set(1, n, 1);
+    assert_eq!(m.spy(4, 2, SpyStyle::Ascii), "+----+\n|   .|\n|.   |\n+----+\n");
+    assert!(svg(&m, 4, 2).contains(r#"<rect x="0" y="1" width="1" height="1""#));
+
+    let max = usize::MAX;
+    let mut m: SparseMatrix = SparseMatrix::new(max, max);
+    m.set(max, max, 1);
+    assert_eq!(m.spy(2, 1, SpyStyle::Ascii), "+--+\n| .|\n+--+\n");
+}
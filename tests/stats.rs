@@ -0,0 +1,62 @@
+use sparse_matrix_proj::SparseMatrix;
+
+// 4x5:
+//   [1 0 0 0 2]
+//   [0 0 0 0 0]
+//   [3 4 0 0 0]
+//   [0 0 5 0 0]
+fn sample() -> SparseMatrix {
+    SparseMatrix::from_triplets(4, 5, vec![(1, 1, 1), (1, 5, 2), (3, 1, 3), (3, 2, 4), (4, 3, 5)])
+}
+
+#[test]
+fn known_answer() {
+    let s = sample().stats();
+    assert_eq!((s.rows, s.cols, s.nnz), (4, 5, 5));
+    assert_eq!(s.density, 0.25);
+    // 每行 2, 0, 2, 1 个; 每列 2, 1, 1, 0, 1 个
+    assert_eq!(s.row_nnz_histogram, vec![1, 1, 2]);
+    assert_eq!(s.col_nnz_histogram, vec![1, 3, 1]);
+    assert_eq!((s.lower_bandwidth, s.upper_bandwidth), (2, 4));
+    // 第 3 行 3 - 1, 第 4 行 4 - 3
+    assert_eq!(s.profile, 3);
+    assert!(!s.is_square && !s.is_pattern_symmetric && !s.is_symmetric && !s.is_symmetric_storage);
+    assert_eq!(s.total_bytes(), s.node_bytes + s.header_bytes);
+    assert_eq!(s.node_bytes % 5, 0);
+}
+
+// 64 位平台上 i32 结点为 Rc 计数 16 字节 + RefCell 标记 8 字节 + 结点 40 字节
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn memory_figures() {
+    let s = sample().stats();
+    assert_eq!(s.node_bytes, 5 * 64);
+    assert_eq!(s.header_bytes, 64 + 16);
+
+    let empty: SparseMatrix = SparseMatrix::new(3, 3);
+    assert_eq!(empty.stats().node_bytes, 0);
+    assert_eq!(empty.stats().header_bytes, 80);
+}
+
+#[test]
+fn symmetric_storage_counts_only_the_lower_triangle() {
+    let m = SparseMatrix::from_triplets(3, 3, vec![(1, 1, 2), (1, 3, 1), (2, 2, 2), (3, 1, 1)]);
+    let general = m.stats();
+    let packed = m.to_symmetric_storage().stats();
+    assert!(general.is_symmetric && general.is_pattern_symmetric && !general.is_symmetric_storage);
+    assert!(packed.is_symmetric && packed.is_symmetric_storage);
+    // 逻辑上的统计不变, 只有结点内存减少
+    assert_eq!(packed.nnz, 4);
+    assert_eq!(packed.row_nnz_histogram, general.row_nnz_histogram);
+    assert_eq!(packed.node_bytes, general.node_bytes / 4 * 3);
+}
+
+#[test]
+fn empty_matrices() {
+    for (rows, cols) in [(0, 0), (0, 3), (2, 0)] {
+        let s = SparseMatrix::<i32>::new(rows, cols).stats();
+        assert_eq!((s.nnz, s.density, s.profile), (0, 0.0, 0));
+        assert_eq!(s.row_nnz_histogram, if rows == 0 { vec![0] } else { vec![rows] });
+        assert_eq!(s.col_nnz_histogram, if cols == 0 { vec![0] } else { vec![cols] });
+    }
+}
@@ -0,0 +1,88 @@
+use sparse_matrix_proj::SparseMatrix;
+
+type Entries = Vec<(usize, usize, i32)>;
+
+fn entries(m: &SparseMatrix) -> Entries {
+    assert!(m.validate().is_ok());
+    m.iter().collect()
+}
+
+// 2x4 与 4x2: 对角线只有 min(rows, cols) 个位置
+fn wide() -> SparseMatrix {
+    SparseMatrix::from_triplets(2, 4, vec![(1, 1, 1), (1, 3, 2), (1, 4, 3), (2, 1, 4), (2, 2, 5), (2, 4, 6)])
+}
+
+fn tall() -> SparseMatrix {
+    SparseMatrix::from_triplets(4, 2, vec![(1, 2, 1), (2, 1, 2), (3, 1, 3), (3, 2, 4), (4, 2, 5)])
+}
+
+#[test]
+fn lower_and_upper_of_non_square_matrices() {
+    let lower = wide().lower();
+    assert_eq!(lower.dims(), (2, 4));
+    assert_eq!(entries(&lower), vec![(1, 1, 1), (2, 1, 4), (2, 2, 5)]);
+    let upper = wide().upper();
+    assert_eq!(upper.dims(), (2, 4));
+    assert_eq!(entries(&upper), vec![(1, 1, 1), (1, 3, 2), (1, 4, 3), (2, 2, 5), (2, 4, 6)]);
+
+    assert_eq!(entries(&tall().lower()), vec![(2, 1, 2), (3, 1, 3), (3, 2, 4), (4, 2, 5)]);
+    assert_eq!(entries(&tall().upper()), vec![(1, 2, 1)]);
+}
+
+#[test]
+fn lower_and_upper_of_symmetric_storage() {
+    // 存储里只有下三角, 但 upper 要给出逻辑上的上三角
+    let m = SparseMatrix::from_symmetric_triplets(3, vec![(1, 1, 1), (2, 1, 2), (3, 1, 3), (3, 3, 4)]);
+    let lower = m.lower();
+    let upper = m.upper();
+    assert!(!lower.is_symmetric_storage());
+    assert!(!upper.is_symmetric_storage());
+    assert_eq!(entries(&lower), vec![(1, 1, 1), (2, 1, 2), (3, 1, 3), (3, 3, 4)]);
+    assert_eq!(entries(&upper), vec![(1, 1, 1), (1, 2, 2), (1, 3, 3), (3, 3, 4)]);
+}
+
+#[test]
+fn symmetry_checks_on_non_square_matrices() {
+    for m in [wide(), tall(), SparseMatrix::new(2, 3)] {
+        assert!(!m.is_symmetric(), "{:?}", m.dims());
+        assert!(!m.is_skew_symmetric(), "{:?}", m.dims());
+    }
+}
+
+#[test]
+fn symmetry_checks_on_square_matrices() {
+    let sym = SparseMatrix::from_triplets(3, 3, vec![(1, 2, 7), (2, 1, 7), (2, 2, 1), (3, 1, -2), (1, 3, -2)]);
+    assert!(sym.is_symmetric());
+    assert!(!sym.is_skew_symmetric());
+
+    let skew = SparseMatrix::from_triplets(3, 3, vec![(1, 2, 7), (2, 1, -7), (1, 3, 2), (3, 1, -2)]);
+    assert!(skew.is_skew_symmetric());
+    assert!(!skew.is_symmetric());
+
+    // 非对角部分反对称, 但对角线上有非零元
+    let almost = SparseMatrix::from_triplets(3, 3, vec![(1, 2, 7), (2, 1, -7), (3, 3, 1)]);
+    assert!(!almost.is_skew_symmetric());
+    assert!(!almost.is_symmetric());
+
+    // 值不对称, 模式对称
+    let pattern_only = SparseMatrix::from_triplets(2, 2, vec![(1, 2, 1), (2, 1, 2)]);
+    assert!(!pattern_only.is_symmetric());
+    assert!(!pattern_only.is_skew_symmetric());
+
+    // 零矩阵既对称又反对称
+    let zero = SparseMatrix::<i32>::new(3, 3);
+    assert!(zero.is_symmetric());
+    assert!(zero.is_skew_symmetric());
+}
+
+#[test]
+fn symmetry_checks_on_symmetric_storage() {
+    let m = SparseMatrix::from_symmetric_triplets(3, vec![(2, 1, 5), (3, 2, -1)]);
+    assert!(m.is_symmetric());
+    assert!(!m.is_skew_symmetric());
+    assert!(m.to_general_storage().is_symmetric());
+
+    let zero = SparseMatrix::<i32>::from_symmetric_triplets(2, vec![]);
+    assert!(zero.is_symmetric());
+    assert!(zero.is_skew_symmetric());
+}